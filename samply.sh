@@ -2,4 +2,4 @@
 set -e
 
 cargo build --profile profiling && clear &&
-  RUST_LOG=info samply record target/profiling/aoc2023 --force-all --n 1000
+  RUST_LOG=info samply record target/profiling/aoc2023 --force-all --n 1000 "$@"
@@ -0,0 +1,181 @@
+/// An answer from an answer file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recorded {
+    pub value: Value,
+    /// Fingerprint of the input the answer was recorded for, see [`crate::util::fingerprint`].
+    pub input: Option<String>,
+}
+
+impl Recorded {
+    /// Answers without a fingerprint are assumed to belong to any input.
+    pub fn is_for(&self, fingerprint: &str) -> bool {
+        self.input
+            .as_deref()
+            .is_none_or(|input| input == fingerprint)
+    }
+}
+
+/// A recorded answer, either in plain text or as a salted hash so it doesn't spoil anyone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Plain(String),
+    Hashed { salt: String, hash: String },
+}
+
+impl Value {
+    /// Hashes the answer with a new random salt.
+    pub fn hashed(answer: &str) -> Self {
+        let salt = new_salt();
+        Value::Hashed {
+            hash: hash(&salt, answer),
+            salt,
+        }
+    }
+
+    pub fn matches(&self, answer: &str) -> bool {
+        match self {
+            Value::Plain(value) => value == answer,
+            Value::Hashed { salt, hash: value } => &hash(salt, answer) == value,
+        }
+    }
+
+    /// The value as written to an answer file.
+    pub fn to_line(&self) -> String {
+        match self {
+            Value::Plain(value) => value.clone(),
+            Value::Hashed { salt, hash } => format!("sha256:{}:{}", salt, hash),
+        }
+    }
+
+    fn from_line(line: &str) -> Self {
+        match line
+            .strip_prefix("sha256:")
+            .and_then(|rest| rest.split_once(':'))
+        {
+            Some((salt, hash)) => Value::Hashed {
+                salt: salt.to_string(),
+                hash: hash.to_string(),
+            },
+            None => Value::Plain(line.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Plain(value) => f.pad(value),
+            Value::Hashed { .. } => f.pad("(hashed)"),
+        }
+    }
+}
+
+fn hash(salt: &str, answer: &str) -> String {
+    use sha2::Digest;
+
+    let digest = sha2::Sha256::digest(format!("{}{}", salt, answer));
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Not cryptographically secure, but enough to tell equal answers apart in the files.
+fn new_salt() -> String {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    format!("{:016x}", hasher.finish())
+}
+
+pub type Answers = (Option<Recorded>, Option<Recorded>);
+
+/// Loads the answers of a day's puzzle input from `answers/NN.txt`.
+pub fn load(files: &crate::files::Files, day: i32) -> anyhow::Result<Answers> {
+    read(&files.answers_path(day))
+}
+
+/// Reads an answer file, one answer per part. A missing file has no answers.
+///
+/// Empty lines and lines starting with `#` are ignored, `-` leaves a part without an answer.
+/// An answer can be bound to its input with the input's fingerprint, as in `1234 @3f2a9c01d4e5b6a7`,
+/// and can be stored as a salted hash, as in `sha256:<salt>:<hash>`.
+pub fn read(path: &std::path::Path) -> anyhow::Result<Answers> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((None, None)),
+        Err(e) => return Err(e.into()),
+    };
+
+    let non_empty_lines: Vec<_> = contents
+        .lines()
+        .filter(|line| is_answer_line(line))
+        .collect();
+    match non_empty_lines.as_slice() {
+        [] => Ok((None, None)),
+        [one] => Ok((parse_line(one), None)),
+        [one, two] => Ok((parse_line(one), parse_line(two))),
+        _ => anyhow::bail!("Invalid answer file '{}'", path.display()),
+    }
+}
+
+/// Writes the answer of a part, keeping comments and the other part's answer.
+///
+/// Returns the answer that was replaced, if any.
+pub fn write(
+    path: &std::path::Path,
+    part: u8,
+    answer: &Recorded,
+) -> anyhow::Result<Option<Recorded>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut lines = contents.lines().map(str::to_string).collect::<Vec<_>>();
+    let mut answer_lines = (0..lines.len())
+        .filter(|&i| is_answer_line(&lines[i]))
+        .collect::<Vec<_>>();
+    if answer_lines.len() > 2 {
+        anyhow::bail!("Invalid answer file '{}'", path.display());
+    }
+
+    let index = (part as usize).saturating_sub(1);
+    while answer_lines.len() <= index {
+        lines.push("-".to_string());
+        answer_lines.push(lines.len() - 1);
+    }
+
+    let line = match &answer.input {
+        Some(input) => format!("{} @{}", answer.value.to_line(), input),
+        None => answer.value.to_line(),
+    };
+    let previous = std::mem::replace(&mut lines[answer_lines[index]], line);
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, lines.join("\n") + "\n")?;
+
+    Ok(parse_line(&previous))
+}
+
+/// Parses an answer line, `None` for `-`.
+fn parse_line(line: &str) -> Option<Recorded> {
+    let (value, input) = match line.rsplit_once(" @") {
+        Some((value, input)) => (value.trim(), Some(input.trim().to_string())),
+        None => (line.trim(), None),
+    };
+    (value != "-").then(|| Recorded {
+        value: Value::from_line(value),
+        input,
+    })
+}
+
+fn is_answer_line(line: &str) -> bool {
+    !line.is_empty() && !line.starts_with('#')
+}
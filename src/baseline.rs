@@ -0,0 +1,149 @@
+use crate::bench::BenchResult;
+
+/// Differences below this are treated as measurement noise, whatever the threshold.
+const MIN_REGRESSION: std::time::Duration = std::time::Duration::from_micros(10);
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+pub struct Baseline {
+    entries: Vec<BaselineEntry>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct BaselineEntry {
+    day: i32,
+    /// `None` for the shared input parsing step.
+    part: Option<u8>,
+    /// Name of the input, baselines from before named inputs only cover the puzzle input.
+    #[serde(default = "puzzle_input")]
+    name: String,
+    /// Fingerprint of the input's contents.
+    input: String,
+    median_ns: u64,
+    p95_ns: u64,
+}
+
+/// Parses the `--max-regression` percentage, which must be finite and not negative.
+pub fn parse_max_regression(s: &str) -> Result<f64, String> {
+    let percent = s
+        .trim()
+        .parse::<f64>()
+        .map_err(|e| format!("Failed to parse percentage '{}': {}", s, e))?;
+    if percent.is_finite() && percent >= 0.0 {
+        Ok(percent)
+    } else {
+        Err(format!(
+            "Percentage must be a finite number, 0 or more: '{}'",
+            s
+        ))
+    }
+}
+
+pub struct Regression {
+    pub day: i32,
+    pub input: String,
+    pub part: Option<u8>,
+    pub baseline: std::time::Duration,
+    pub current: std::time::Duration,
+}
+
+impl Baseline {
+    pub fn load(path: &std::path::Path) -> anyhow::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Ok(Some(serde_json::from_str(&contents).map_err(|e| {
+                anyhow::anyhow!("Failed to parse baseline '{}': {}", path.display(), e)
+            })?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    /// Replaces the entries of all benchmarked days, inputs and parts, keeping the others.
+    pub fn update(&mut self, results: &[(BenchResult, String)]) {
+        for (result, fingerprint) in results {
+            let entry = BaselineEntry {
+                day: result.day,
+                part: result.part,
+                name: result.input.clone(),
+                input: fingerprint.clone(),
+                median_ns: result.stats.median.as_nanos() as u64,
+                p95_ns: result.stats.p95.as_nanos() as u64,
+            };
+
+            match self.entries.iter_mut().find(|e| e.key() == entry.key()) {
+                Some(existing) => *existing = entry,
+                None => self.entries.push(entry),
+            }
+        }
+
+        self.entries
+            .sort_by(|a, b| (a.day, &a.name, a.part).cmp(&(b.day, &b.name, b.part)));
+    }
+
+    /// Compares median runtimes against the baseline.
+    ///
+    /// Parts whose input changed since they were recorded are skipped.
+    pub fn compare(
+        &self,
+        results: &[(BenchResult, String)],
+        threshold_percent: f64,
+    ) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+
+        for (result, fingerprint) in results {
+            let entry = match self
+                .entries
+                .iter()
+                .find(|e| e.key() == (result.day, result.input.as_str(), result.part))
+            {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            if &entry.input != fingerprint {
+                log::info!(
+                    "{}: baseline was recorded for a different input, skipping",
+                    result.label()
+                );
+                continue;
+            }
+
+            let baseline = std::time::Duration::from_nanos(entry.median_ns);
+            let current = result.stats.median;
+            // A huge threshold can't overflow, it just never fails
+            let limit = std::time::Duration::try_from_secs_f64(
+                baseline.as_secs_f64() * (1.0 + threshold_percent / 100.0),
+            )
+            .unwrap_or(std::time::Duration::MAX);
+
+            if current > limit && current - baseline > MIN_REGRESSION {
+                regressions.push(Regression {
+                    day: result.day,
+                    input: result.input.clone(),
+                    part: result.part,
+                    baseline,
+                    current,
+                });
+            }
+        }
+
+        regressions
+    }
+}
+
+impl BaselineEntry {
+    fn key(&self) -> (i32, &str, Option<u8>) {
+        (self.day, &self.name, self.part)
+    }
+}
+
+fn puzzle_input() -> String {
+    aoc2023::inputs::PUZZLE_INPUT.to_string()
+}
@@ -0,0 +1,172 @@
+use aoc2023::{Params, Solver};
+
+pub struct BenchResult {
+    pub day: i32,
+    pub input: String,
+    /// `None` for the shared input parsing step.
+    pub part: Option<u8>,
+    pub stats: Stats,
+}
+
+pub struct Stats {
+    pub samples: usize,
+    pub min: std::time::Duration,
+    pub median: std::time::Duration,
+    pub mean: std::time::Duration,
+    pub std_dev: std::time::Duration,
+    pub p95: std::time::Duration,
+    /// Samples outside of Tukey's fences (1.5 IQR below Q1 or above Q3).
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<std::time::Duration>) -> anyhow::Result<Self> {
+        if samples.is_empty() {
+            anyhow::bail!("Failed to compute stats of empty sample set");
+        }
+        samples.sort();
+
+        let n = samples.len();
+        let secs = samples.iter().map(|d| d.as_secs_f64()).collect::<Vec<_>>();
+
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let q1 = percentile(&secs, 0.25);
+        let q3 = percentile(&secs, 0.75);
+        let iqr = q3 - q1;
+        let (lo_fence, hi_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let outliers = secs
+            .iter()
+            .filter(|&&s| s < lo_fence || s > hi_fence)
+            .count();
+
+        Ok(Self {
+            samples: n,
+            min: samples[0],
+            median: std::time::Duration::from_secs_f64(percentile(&secs, 0.5)),
+            mean: std::time::Duration::from_secs_f64(mean),
+            std_dev: std::time::Duration::from_secs_f64(variance.sqrt()),
+            p95: std::time::Duration::from_secs_f64(percentile(&secs, 0.95)),
+            outliers,
+        })
+    }
+
+    /// More than 10% of the samples are outliers, so the numbers should not be trusted.
+    pub fn is_noisy(&self) -> bool {
+        self.outliers * 10 > self.samples
+    }
+}
+
+/// Linear interpolation between the closest ranks of the sorted samples.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+pub fn bench_day(
+    day: i32,
+    input: &aoc2023::inputs::Input,
+    solution: &dyn Solver,
+    params: &Params,
+    parts: &[u8],
+    warmup: u32,
+    samples: u32,
+) -> anyhow::Result<Vec<BenchResult>> {
+    let mut results = Vec::with_capacity(parts.len() + 1);
+
+    let stats = measure(warmup, samples, || solution.parse(&input.text).map(|_| ()))?;
+    results.push(BenchResult {
+        day,
+        input: input.name.clone(),
+        part: None,
+        stats,
+    });
+
+    let parsed = solution.parse(&input.text)?;
+    for &part in parts {
+        let stats = match part {
+            1 => measure(warmup, samples, || parsed.part_one(params).map(|_| ()))?,
+            2 => measure(warmup, samples, || parsed.part_two(params).map(|_| ()))?,
+            _ => anyhow::bail!("Invalid part: {}", part),
+        };
+        results.push(BenchResult {
+            day,
+            input: input.name.clone(),
+            part: Some(part),
+            stats,
+        });
+    }
+
+    Ok(results)
+}
+
+fn measure(
+    warmup: u32,
+    samples: u32,
+    mut f: impl FnMut() -> anyhow::Result<()>,
+) -> anyhow::Result<Stats> {
+    for _ in 0..warmup {
+        f()?;
+    }
+
+    let mut durations = Vec::with_capacity(samples as usize);
+    for _ in 0..samples {
+        let t0 = std::time::Instant::now();
+        f()?;
+        durations.push(t0.elapsed());
+    }
+
+    Stats::from_samples(durations)
+}
+
+pub fn print_report(results: &[&BenchResult]) {
+    println!(
+        "{:<4} {:<12} {:<6} {:>10} {:>10} {:>10} {:>10} {:>10} {:>9}",
+        "day", "input", "part", "min", "median", "mean", "std dev", "p95", "outliers"
+    );
+
+    for result in results {
+        let part = result
+            .part
+            .map_or_else(|| "parse".to_string(), |part| part.to_string());
+        let stats = &result.stats;
+
+        println!(
+            "{:<4} {:<12} {:<6} {:>10} {:>10} {:>10} {:>10} {:>10} {:>9}",
+            format!("{:02}", result.day),
+            result.input,
+            part,
+            format_ms(stats.min),
+            format_ms(stats.median),
+            format_ms(stats.mean),
+            format_ms(stats.std_dev),
+            format_ms(stats.p95),
+            format!(
+                "{}{}",
+                stats.outliers,
+                if stats.is_noisy() { " (!)" } else { "" }
+            ),
+        );
+    }
+
+    for result in results.iter().filter(|result| result.stats.is_noisy()) {
+        log::warn!(
+            "{} has {} outliers in {} samples, results are noisy",
+            result.label(),
+            result.stats.outliers,
+            result.stats.samples
+        );
+    }
+}
+
+impl BenchResult {
+    pub fn label(&self) -> String {
+        crate::report::label(self.day, &self.input, self.part)
+    }
+}
+
+pub fn format_ms(duration: std::time::Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
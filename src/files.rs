@@ -0,0 +1,71 @@
+/// Locations of the puzzle inputs, parameters, examples and answers.
+pub struct Files {
+    input_dir: std::path::PathBuf,
+    answers_dir: std::path::PathBuf,
+    /// Read from `--input`, replaces the input file of the selected day.
+    input: Option<String>,
+}
+
+impl Default for Files {
+    fn default() -> Self {
+        Self::new("input".into(), "answers".into())
+    }
+}
+
+impl Files {
+    pub fn new(input_dir: std::path::PathBuf, answers_dir: std::path::PathBuf) -> Self {
+        Self {
+            input_dir,
+            answers_dir,
+            input: None,
+        }
+    }
+
+    pub fn set_input(&mut self, input: String) {
+        self.input = Some(input);
+    }
+
+    pub fn input_path(&self, day: i32) -> std::path::PathBuf {
+        self.input_dir.join(format!("{:02}.txt", day))
+    }
+
+    pub fn has_input_file(&self, day: i32) -> bool {
+        self.input.is_some() || self.input_path(day).is_file()
+    }
+
+    pub fn is_overridden(&self) -> bool {
+        self.input.is_some()
+    }
+
+    pub fn read_input(&self, day: i32) -> anyhow::Result<String> {
+        if let Some(input) = &self.input {
+            return Ok(input.clone());
+        }
+
+        let path = self.input_path(day);
+        std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))
+    }
+
+    pub fn params_path(&self, day: i32) -> std::path::PathBuf {
+        self.input_dir.join(format!("{:02}.params", day))
+    }
+
+    pub fn answers_path(&self, day: i32) -> std::path::PathBuf {
+        self.answers_dir.join(format!("{:02}.txt", day))
+    }
+
+    /// Directory with the examples and other named inputs of a day.
+    pub fn inputs_dir(&self, day: i32) -> std::path::PathBuf {
+        self.input_dir.join(format!("{:02}", day))
+    }
+
+    /// Directory with the answers to the examples and other named inputs of a day.
+    pub fn named_answers_dir(&self, day: i32) -> std::path::PathBuf {
+        self.answers_dir.join(format!("{:02}", day))
+    }
+
+    pub fn named_answers_path(&self, day: i32, name: &str) -> std::path::PathBuf {
+        self.named_answers_dir(day).join(format!("{}.txt", name))
+    }
+}
@@ -0,0 +1,189 @@
+use crate::report::{PartReport, Status};
+
+/// One part of one run, stored as a line of JSON in the history file.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Entry {
+    /// Seconds since the Unix epoch.
+    timestamp: u64,
+    /// Short hash of the checked out commit, with a `-dirty` suffix for uncommitted changes.
+    commit: Option<String>,
+    day: i32,
+    part: u8,
+    input: String,
+    status: Status,
+    /// Missing in entries recorded before it was added.
+    #[serde(default)]
+    hard_coded: bool,
+    parse_ms: f64,
+    solve_ms: f64,
+}
+
+/// Appends the reports of a run to the history file.
+pub fn append(path: &std::path::Path, reports: &[PartReport]) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
+    let commit = git_commit();
+
+    let mut lines = String::new();
+    for r in reports {
+        let entry = Entry {
+            timestamp,
+            commit: commit.clone(),
+            day: r.day,
+            part: r.part,
+            input: r.input.clone(),
+            status: r.status,
+            hard_coded: r.hard_coded,
+            parse_ms: crate::report::as_ms(r.parse_time),
+            solve_ms: crate::report::as_ms(r.solve_time),
+        };
+        lines += &(serde_json::to_string(&entry)? + "\n");
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| anyhow::anyhow!("Failed to write '{}': {}", path.display(), e))?;
+    log::info!("Recorded {} parts in '{}'", reports.len(), path.display());
+    Ok(())
+}
+
+/// The parts of the latest recorded run of each day and part, as reports without answers.
+pub fn latest(path: &std::path::Path) -> anyhow::Result<Vec<PartReport>> {
+    let entries = read(path)?;
+
+    let mut latest = hashbrown::HashMap::new();
+    for e in entries.iter() {
+        let timestamp = latest.entry((e.day, e.part)).or_insert(e.timestamp);
+        *timestamp = e.timestamp.max(*timestamp);
+    }
+
+    Ok(entries
+        .into_iter()
+        .filter(|e| latest[&(e.day, e.part)] == e.timestamp)
+        .map(|e| PartReport {
+            day: e.day,
+            part: e.part,
+            input: e.input,
+            answer: None,
+            expected: None,
+            status: e.status,
+            hard_coded: e.hard_coded,
+            parse_time: std::time::Duration::from_secs_f64(e.parse_ms / 1000.0),
+            solve_time: std::time::Duration::from_secs_f64(e.solve_ms / 1000.0),
+            error: None,
+        })
+        .collect())
+}
+
+/// Prints how the runtime of a day's parts changed over the recorded runs, oldest first.
+pub fn print(path: &std::path::Path, day: i32, part: Option<u8>) -> anyhow::Result<()> {
+    let entries = read(path)?;
+    if entries.is_empty() {
+        anyhow::bail!("No history recorded yet in '{}'", path.display());
+    }
+
+    let entries = entries
+        .into_iter()
+        .filter(|entry| entry.day == day && part.is_none_or(|p| p == entry.part))
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        anyhow::bail!("No history recorded for day {}", day);
+    }
+
+    println!(
+        "{:<17} {:<14} {:<5} {:<10} {:<15} {:>10}  change",
+        "date", "commit", "part", "input", "status", "solve"
+    );
+
+    // Changes are relative to the previous run of the same part and input
+    let mut previous = hashbrown::HashMap::new();
+    for e in entries.iter() {
+        let change = match previous.insert((e.part, e.input.as_str()), e.solve_ms) {
+            Some(before) if before > 0.0 => format!("{:+.1}%", (e.solve_ms / before - 1.0) * 100.0),
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:<17} {:<14} {:<5} {:<10} {:<15} {:>8.3}ms  {}",
+            format_timestamp(e.timestamp),
+            e.commit.as_deref().unwrap_or("-"),
+            e.part,
+            e.input,
+            e.status,
+            e.solve_ms,
+            change
+        );
+    }
+    Ok(())
+}
+
+/// Reads all entries, oldest first. A missing file has none.
+fn read(path: &std::path::Path) -> anyhow::Result<Vec<Entry>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut entries = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(serde_json::from_str(line).map_err(|e| {
+            anyhow::anyhow!("Failed to parse '{}' line {}: {}", path.display(), i + 1, e)
+        })?);
+    }
+    Ok(entries)
+}
+
+/// `None` outside of a git checkout.
+fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => Some(commit + "-dirty"),
+        _ => Some(commit),
+    }
+}
+
+/// Formats as `YYYY-MM-DD hh:mm` in UTC.
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
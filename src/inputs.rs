@@ -0,0 +1,139 @@
+/// Name of the puzzle input in `input/NN.txt`, as opposed to the named inputs in `input/NN/`.
+pub const PUZZLE_INPUT: &str = "input";
+
+/// A puzzle input, with its parameters and the expected answers.
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    pub params: crate::problems::Params,
+    pub answers: crate::answers::Answers,
+}
+
+impl Input {
+    pub fn answer(&self, part: u8) -> Option<&crate::answers::Recorded> {
+        match part {
+            1 => self.answers.0.as_ref(),
+            _ => self.answers.1.as_ref(),
+        }
+    }
+
+    pub fn fingerprint(&self) -> String {
+        crate::util::fingerprint(&self.text)
+    }
+
+    /// Both parts have an answer recorded for this input.
+    pub fn is_solved(&self) -> bool {
+        let fingerprint = self.fingerprint();
+        [1, 2].into_iter().all(|part| {
+            self.answer(part)
+                .is_some_and(|answer| answer.is_for(&fingerprint))
+        })
+    }
+
+    /// Examples come from the puzzle description and are named `example-N`.
+    pub fn is_example(&self) -> bool {
+        is_example(&self.name)
+    }
+}
+
+fn is_example(name: &str) -> bool {
+    name.starts_with("example-")
+}
+
+/// Loads the puzzle inputs of a day: `input/NN.txt` and all other inputs in `input/NN/`,
+/// for example those of other accounts.
+///
+/// Only the `--input` override is loaded if there is one.
+pub fn load(files: &crate::files::Files, day: i32) -> anyhow::Result<Vec<Input>> {
+    let mut inputs = Vec::new();
+
+    if files.has_input_file(day) {
+        inputs.push(Input {
+            name: PUZZLE_INPUT.to_string(),
+            text: files.read_input(day)?,
+            params: crate::problems::Params::read(&files.params_path(day))?,
+            answers: crate::answers::load(files, day)?,
+        });
+    }
+
+    if !files.is_overridden() {
+        inputs.extend(load_named(files, day, |name| !is_example(name))?);
+    }
+
+    Ok(inputs)
+}
+
+/// Finds a single input by name, `input` being the one in `input/NN.txt`.
+pub fn find(files: &crate::files::Files, day: i32, name: &str) -> anyhow::Result<Input> {
+    let input = if name == PUZZLE_INPUT {
+        load(files, day)?
+            .into_iter()
+            .find(|input| input.name == PUZZLE_INPUT)
+    } else {
+        load_named(files, day, |n| n == name)?.into_iter().next()
+    };
+
+    input.ok_or_else(|| anyhow::anyhow!("No input named '{}' for day {}", name, day))
+}
+
+/// Loads `input/NN/example-*.txt`.
+pub fn load_examples(files: &crate::files::Files, day: i32) -> anyhow::Result<Vec<Input>> {
+    load_named(files, day, is_example)
+}
+
+pub fn has_examples(files: &crate::files::Files, day: i32) -> bool {
+    load_examples(files, day).is_ok_and(|examples| !examples.is_empty())
+}
+
+/// Loads `input/NN/<name>.txt`, with the answers from `answers/NN/<name>.txt`
+/// and the parameters from `input/NN/<name>.params`.
+fn load_named(
+    files: &crate::files::Files,
+    day: i32,
+    filter: impl Fn(&str) -> bool,
+) -> anyhow::Result<Vec<Input>> {
+    let mut names = named_inputs(files, day)?;
+    names.retain(|name| filter(name));
+
+    let dir = files.inputs_dir(day);
+    names
+        .into_iter()
+        .map(|name| {
+            let text = std::fs::read_to_string(dir.join(format!("{}.txt", name)))?;
+            let params = crate::problems::Params::read(&dir.join(format!("{}.params", name)))?;
+            let answers = crate::answers::read(&files.named_answers_path(day, &name))?;
+            Ok(Input {
+                name,
+                text,
+                params,
+                answers,
+            })
+        })
+        .collect()
+}
+
+/// Names of the inputs in `input/NN/`, in order.
+fn named_inputs(files: &crate::files::Files, day: i32) -> anyhow::Result<Vec<String>> {
+    let entries = match std::fs::read_dir(files.inputs_dir(day)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let file_name = entry?.file_name();
+        if let Some(name) = file_name.to_string_lossy().strip_suffix(".txt") {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+
+    Ok(names)
+}
+
+/// Whether the day has a puzzle input, either `input/NN.txt` or one in `input/NN/`.
+pub fn has_input(files: &crate::files::Files, day: i32) -> bool {
+    files.has_input_file(day)
+        || named_inputs(files, day).is_ok_and(|names| names.iter().any(|name| !is_example(name)))
+}
@@ -0,0 +1,40 @@
+#![feature(linked_list_remove)]
+
+//! Advent of Code 2023 solutions, usable without the CLI.
+//!
+//! ```no_run
+//! let input = std::fs::read_to_string("input/01.txt")?;
+//! let answer = aoc2023::solve(1, 2, &input)?;
+//! # anyhow::Ok(())
+//! ```
+
+pub mod answers;
+pub mod files;
+pub mod inputs;
+pub mod problems;
+pub mod util;
+
+pub use problems::{solution, Answer, Params, Parsed, Solution, Solver};
+
+/// Solves one part of a day's puzzle with the default parameters.
+pub fn solve(day: i32, part: u8, input: &str) -> anyhow::Result<Answer> {
+    solve_with_params(day, part, input, &Params::default())
+}
+
+/// Solves one part of a day's puzzle, overriding some of its parameters.
+pub fn solve_with_params(
+    day: i32,
+    part: u8,
+    input: &str,
+    params: &Params,
+) -> anyhow::Result<Answer> {
+    let solution =
+        solution(day).ok_or_else(|| anyhow::anyhow!("No problem set mapped for day {}", day))?;
+    let parsed = solution.parse(input)?;
+
+    match part {
+        1 => parsed.part_one(params),
+        2 => parsed.part_two(params),
+        _ => anyhow::bail!("Invalid part: {}", part),
+    }
+}
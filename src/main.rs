@@ -1,164 +1,526 @@
-#![feature(linked_list_remove)]
+mod baseline;
+mod bench;
+mod history;
+mod record;
+mod report;
+mod scaffold;
+mod status;
+mod watch;
+mod watchdog;
 
-mod problems;
-mod util;
+use aoc2023::{files, inputs, problems};
+use aoc2023::{Answer, Params, Parsed, Solver};
 
 fn main() -> anyhow::Result<()> {
     env_logger::init();
     let args = <Args as clap::Parser>::parse();
 
+    let mut files = files::Files::new(args.input_dir.clone(), args.answers_dir.clone());
+
+    match args.command {
+        Some(Command::New { day }) => return scaffold::new_day(&files, day),
+        Some(Command::Record {
+            day,
+            part,
+            answer,
+            name,
+            plain,
+        }) => return record::record(&files, day, part, &name, answer, plain, &args.params),
+        Some(Command::History { day, part }) => return history::print(&args.history, day, part),
+        Some(Command::Status { run }) => {
+            // Days without a recorded run are run and validated, whatever the other flags say
+            let args = Args {
+                validate: true,
+                force_all: true,
+                examples: false,
+                bench: false,
+                ..args
+            };
+            let days = days_to_run(&args, &files)?;
+
+            let mut reports = if run {
+                Vec::new()
+            } else {
+                history::latest(&args.history)?
+            };
+            reports.retain(|r| days.contains(&r.day));
+            let unrecorded = days
+                .iter()
+                .copied()
+                .filter(|&day| {
+                    args.parts()
+                        .any(|part| !reports.iter().any(|r| r.day == day && r.part == part))
+                })
+                .collect::<Vec<_>>();
+            if !unrecorded.is_empty() {
+                log::info!("Running {} days without a recorded run", unrecorded.len());
+                reports.retain(|r| !unrecorded.contains(&r.day));
+                let mut total_duration = std::time::Duration::ZERO;
+                reports.extend(run_days(&args, &files, &unrecorded, &mut total_duration));
+            }
+
+            status::print(&reports);
+            return Ok(());
+        }
+        None => {}
+    }
+
+    if let Some(path) = &args.input {
+        files.set_input(read_input(path)?);
+    }
+
     let mut total_duration = std::time::Duration::ZERO;
+    let mut reports = Vec::new();
 
-    let days_to_run = days_to_run(args.force_all);
+    let days_to_run = days_to_run(&args, &files)?;
     if days_to_run.is_empty() {
         log::warn!("No problems to run. Did you forget to create input files?");
+    } else if args.bench {
+        run_benchmarks(&args, &files, &days_to_run)?;
+    } else if args.watch {
+        watch::watch(&files, &days_to_run, |day| {
+            run_days(&args, &files, &[day], &mut total_duration)
+        })?;
     } else {
         for _ in 0..args.n {
-            for &day in days_to_run.iter() {
-                let (input, one, two) = load_problem_set(day)?;
+            // Only the last round is reported
+            reports = run_days(&args, &files, &days_to_run, &mut total_duration);
+        }
+    }
+
+    log::debug!("Total duration: {:?}", total_duration);
+
+    if args.record_history && !reports.is_empty() {
+        history::append(&args.history, &reports)?;
+    }
+
+    if let Some(format) = args.format {
+        report::print(&reports, format)?;
+    }
+
+    if args.validates() {
+        report::print_summary(&reports);
+    }
 
-                let answers = if args.validate {
-                    load_answers(day)?
-                } else {
-                    (None, None)
+    let failures = reports.iter().filter(|r| r.status.is_failure()).count();
+    if failures > 0 {
+        anyhow::bail!("{} parts failed", failures);
+    }
+
+    Ok(())
+}
+
+/// Runs the selected parts of the days, reporting a day that fails to load as an error of each part.
+fn run_days(
+    args: &Args,
+    files: &files::Files,
+    days: &[i32],
+    total_duration: &mut std::time::Duration,
+) -> Vec<report::PartReport> {
+    let mut reports = Vec::new();
+    for &day in days.iter() {
+        match run_day(args, files, day) {
+            Ok((day_reports, duration)) => {
+                *total_duration += duration;
+                reports.extend(day_reports);
+            }
+            Err(e) => {
+                log::error!("day {:02} failed: {:#}", day, e);
+                reports.extend(args.parts().map(|part| {
+                    report::PartReport::new(
+                        day,
+                        part,
+                        inputs::PUZZLE_INPUT,
+                        Err(anyhow::anyhow!("{:#}", e)),
+                        report::Expected::Unchecked,
+                        std::time::Duration::ZERO,
+                        std::time::Duration::ZERO,
+                    )
+                }));
+            }
+        }
+    }
+    reports
+}
+
+/// Runs the selected parts of a day against all of its inputs,
+/// returning their reports and the total time taken.
+fn run_day(
+    args: &Args,
+    files: &files::Files,
+    day: i32,
+) -> anyhow::Result<(Vec<report::PartReport>, std::time::Duration)> {
+    let solution = problems::solution(day)
+        .ok_or_else(|| anyhow::anyhow!("No problem set mapped for day {}", day))?;
+
+    let inputs = if args.examples {
+        inputs::load_examples(files, day)?
+    } else {
+        inputs::load(files, day)?
+    };
+
+    let mut reports = Vec::new();
+    let mut duration = std::time::Duration::ZERO;
+    for input in inputs {
+        let fingerprint = input.fingerprint();
+        let parts = args
+            .parts()
+            .filter_map(|part| {
+                let expected = match input.answer(part) {
+                    _ if !args.validates() => report::Expected::Unchecked,
+                    // Examples usually only cover one of the parts
+                    None if input.is_example() => return None,
+                    None => report::Expected::Unsolved,
+                    Some(answer) if answer.is_for(&fingerprint) => {
+                        report::Expected::Answer(answer.value.clone())
+                    }
+                    Some(answer) => report::Expected::OtherInput(answer.value.clone()),
                 };
+                Some((part, expected))
+            })
+            .collect();
+
+        let params = args.override_params(input.params);
+        let (input_reports, input_duration) = run_input(
+            args,
+            day,
+            &input.name,
+            &input.text,
+            solution,
+            &params,
+            parts,
+        );
+        reports.extend(input_reports);
+        duration += input_duration;
+    }
 
-                total_duration +=
-                    run_solution(day, 1, &input, one, args.show_solutions, answers.0)?;
-                total_duration +=
-                    run_solution(day, 2, &input, two, args.show_solutions, answers.1)?;
+    Ok((reports, duration))
+}
+
+/// Runs the given parts against a single input, together with their expected answers.
+fn run_input(
+    args: &Args,
+    day: i32,
+    input_name: &str,
+    input: &str,
+    solution: &dyn Solver,
+    params: &Params,
+    parts: Vec<(u8, report::Expected)>,
+) -> (Vec<report::PartReport>, std::time::Duration) {
+    let parse_label = report::label(day, input_name, None);
+    let (parsed, parse_duration) = parse_input(&parse_label, input, solution);
+    let parsed = parsed.map(std::sync::Arc::<dyn Parsed>::from);
+    let mut duration = parse_duration;
+    if let Err(e) = &parsed {
+        log::error!("{} failed: {:#}", parse_label, e);
+    }
+
+    let mut reports = Vec::with_capacity(parts.len());
+    for (part, expected) in parts {
+        let report = match &parsed {
+            Ok(parsed) => {
+                let (parsed, params) = (parsed.clone(), params.clone());
+                run_solution(
+                    args,
+                    day,
+                    input_name,
+                    part,
+                    move || match part {
+                        1 => parsed.part_one(&params),
+                        _ => parsed.part_two(&params),
+                    },
+                    expected,
+                    parse_duration,
+                )
             }
+            Err(e) => report::PartReport::new(
+                day,
+                part,
+                input_name,
+                Err(anyhow::anyhow!("Failed to parse input: {:#}", e)),
+                expected,
+                parse_duration,
+                std::time::Duration::ZERO,
+            ),
+        };
+
+        duration += report.solve_time;
+        reports.push(report);
+    }
+
+    (reports, duration)
+}
+
+fn run_benchmarks(args: &Args, files: &files::Files, days: &[i32]) -> anyhow::Result<()> {
+    let parts = args.parts().collect::<Vec<_>>();
+
+    let mut results = Vec::new();
+    for &day in days {
+        let solution = problems::solution(day)
+            .ok_or_else(|| anyhow::anyhow!("No problem set mapped for day {}", day))?;
+        let inputs = inputs::load(files, day)?;
+        if inputs.is_empty() {
+            anyhow::bail!("No input to benchmark for day {}", day);
+        }
+
+        for input in inputs {
+            let params = args.override_params(input.params.clone());
+            let fingerprint = input.fingerprint();
+            results.extend(
+                bench::bench_day(
+                    day,
+                    &input,
+                    solution,
+                    &params,
+                    &parts,
+                    args.warmup,
+                    args.samples,
+                )?
+                .into_iter()
+                .map(|result| (result, fingerprint.clone())),
+            );
         }
     }
 
-    log::debug!("Total duration: {:?}", total_duration);
+    bench::print_report(&results.iter().map(|(r, _)| r).collect::<Vec<_>>());
+
+    let baseline = baseline::Baseline::load(&args.baseline)?;
+
+    if args.save_baseline {
+        let mut baseline = baseline.unwrap_or_default();
+        baseline.update(&results);
+        baseline.save(&args.baseline)?;
+        log::info!("Saved baseline to '{}'", args.baseline.display());
+    } else if let Some(baseline) = baseline {
+        let regressions = baseline.compare(&results, args.max_regression);
+        for regression in regressions.iter() {
+            log::error!(
+                "{} regressed: {} -> {}",
+                report::label(regression.day, &regression.input, regression.part),
+                bench::format_ms(regression.baseline),
+                bench::format_ms(regression.current),
+            );
+        }
+
+        if !regressions.is_empty() {
+            anyhow::bail!(
+                "{} parts are more than {}% slower than the baseline",
+                regressions.len(),
+                args.max_regression
+            );
+        }
+    }
 
     Ok(())
 }
 
-fn load_problem_set(day: i32) -> anyhow::Result<(String, SolutionFn, SolutionFn)> {
-    let (one, two): (SolutionFn, SolutionFn) = match day {
-        0 => (problems::day_00::part_one, problems::day_00::part_two),
-        1 => (problems::day_01::part_one, problems::day_01::part_two),
-        2 => (problems::day_02::part_one, problems::day_02::part_two),
-        3 => (problems::day_03::part_one, problems::day_03::part_two),
-        4 => (problems::day_04::part_one, problems::day_04::part_two),
-        5 => (problems::day_05::part_one, problems::day_05::part_two),
-        6 => (problems::day_06::part_one, problems::day_06::part_two),
-        7 => (problems::day_07::part_one, problems::day_07::part_two),
-        8 => (problems::day_08::part_one, problems::day_08::part_two),
-        9 => (problems::day_09::part_one, problems::day_09::part_two),
-        10 => (problems::day_10::part_one, problems::day_10::part_two),
-        11 => (problems::day_11::part_one, problems::day_11::part_two),
-        12 => (problems::day_12::part_one, problems::day_12::part_two),
-        13 => (problems::day_13::part_one, problems::day_13::part_two),
-        14 => (problems::day_14::part_one, problems::day_14::part_two),
-        15 => (problems::day_15::part_one, problems::day_15::part_two),
-        16 => (problems::day_16::part_one, problems::day_16::part_two),
-        17 => (problems::day_17::part_one, problems::day_17::part_two),
-        18 => (problems::day_18::part_one, problems::day_18::part_two),
-        19 => (problems::day_19::part_one, problems::day_19::part_two),
-        20 => (problems::day_20::part_one, problems::day_20::part_two),
-        21 => (problems::day_21::part_one, problems::day_21::part_two),
-        22 => (problems::day_22::part_one, problems::day_22::part_two),
-        23 => (problems::day_23::part_one, problems::day_23::part_two),
-        24 => (problems::day_24::part_one, problems::day_24::part_two),
-        25 => (problems::day_25::part_one, problems::day_25::part_two),
-        _ => anyhow::bail!("No problem set mapped for day {}", day),
-    };
+/// Reads the `--input` file, or stdin for `-`.
+fn read_input(path: &std::path::Path) -> anyhow::Result<String> {
+    if path == std::path::Path::new("-") {
+        return Ok(std::io::read_to_string(std::io::stdin())?);
+    }
 
-    let input = {
-        let path = format!("./input/{:02}.txt", day);
-        std::fs::read_to_string(path)?
-    };
+    std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))
+}
+
+fn parse_input(
+    label: &str,
+    input: &str,
+    solution: &dyn Solver,
+) -> (anyhow::Result<Box<dyn Parsed>>, std::time::Duration) {
+    measure_time::debug_time!("{}", label);
+
+    let t0 = std::time::Instant::now();
+    let parsed = catch_panic(|| solution.parse(input));
+    let duration = t0.elapsed();
 
-    Ok((input, one, two))
+    (parsed, duration)
 }
 
 fn run_solution(
+    args: &Args,
     day: i32,
-    part: i32,
-    input: &str,
-    f: SolutionFn,
-    show_solution: bool,
-    expected_answer: Option<String>,
-) -> anyhow::Result<std::time::Duration> {
-    let (answer, duration) = {
-        measure_time::debug_time!("day {:02}/part {}", day, part);
+    input_name: &str,
+    part: u8,
+    f: impl FnOnce() -> anyhow::Result<Answer> + Send + 'static,
+    expected: report::Expected,
+    parse_duration: std::time::Duration,
+) -> report::PartReport {
+    let label = report::label(day, input_name, Some(part));
+    let (result, duration) = {
+        measure_time::debug_time!("{}", label);
 
         let t0 = std::time::Instant::now();
-        let answer = f(input)?;
+        let result = match args.timeout(day) {
+            Some(timeout) => {
+                let name = format!("day-{:02}-part-{}", day, part);
+                watchdog::run(name, timeout, move || catch_panic(f))
+            }
+            None => catch_panic(f),
+        };
         let duration = t0.elapsed();
 
-        (answer, duration)
+        (result, duration)
     };
 
-    if show_solution {
-        log::info!("day {:02}/part {} => {}", day, part, answer);
+    let report = report::PartReport::new(
+        day,
+        part,
+        input_name,
+        result,
+        expected,
+        parse_duration,
+        duration,
+    );
+
+    match (&report.answer, &report.error) {
+        (Some(answer), _) if args.show_solutions => log::info!("{} => {}", label, answer),
+        (_, Some(error)) => log::error!("{} failed: {}", label, error),
+        _ => {}
     }
 
-    if let Some(expected) = expected_answer {
-        if answer != expected {
-            log::error!(
-                "day {:02}/part {} => expected: {}, actual: {}",
-                day,
-                part,
-                expected,
-                answer
-            );
-        }
+    if report.status != report::Status::Timeout && duration > watchdog::PERFORMANCE_TARGET {
+        log::warn!(
+            "{} took {:?}, above the {:?} performance target",
+            label,
+            duration,
+            watchdog::PERFORMANCE_TARGET
+        );
     }
 
-    Ok(duration)
+    if report.status == report::Status::Fail {
+        log::error!(
+            "{} => expected: {}, actual: {}",
+            label,
+            report.expected.as_deref().unwrap_or_default(),
+            report.answer.as_deref().unwrap_or_default()
+        );
+    }
+
+    report
 }
 
-fn load_answers(day: i32) -> anyhow::Result<(Option<String>, Option<String>)> {
-    let path = format!("./answers/{:02}.txt", day);
-    let contents = std::fs::read_to_string(path);
-    contents.map_or(Ok((None, None)), |contents| {
-        let non_empty_lines: Vec<_> = contents
-            .lines()
-            .filter(|line| !line.is_empty() && !line.starts_with('#'))
-            .collect();
-        match non_empty_lines.as_slice() {
-            [] => Ok((None, None)),
-            [one] => Ok((Some(one.to_string()), None)),
-            [one, two] => Ok((Some(one.to_string()), Some(two.to_string()))),
-            _ => anyhow::bail!("Invalid answer file for day {}", day),
-        }
+/// Turns a panic into an error, so that one failing part doesn't abort the whole run.
+fn catch_panic<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_string());
+        Err(anyhow::anyhow!("panicked: {}", message))
     })
 }
 
-type SolutionFn = fn(&str) -> anyhow::Result<String>;
+fn days_to_run(args: &Args, files: &files::Files) -> anyhow::Result<Vec<i32>> {
+    if !args.days.is_empty() {
+        let days = selected_days(&args.days, files, args.examples)?;
+        if args.input.is_some() && days.len() != 1 {
+            anyhow::bail!("--input can only be used with a single day");
+        }
+        return Ok(days);
+    }
+
+    if args.examples {
+        return Ok((0..=25)
+            .filter(|&day| problems::solution(day).is_some() && inputs::has_examples(files, day))
+            .collect());
+    }
 
-fn days_to_run(force_all: bool) -> Vec<i32> {
     let days_to_run: Vec<_> = {
-        let days_with_input_file = (1..=25).filter(|day| {
-            let input_path = format!("./input/{:02}.txt", day);
-            std::fs::metadata(input_path).is_ok()
-        });
+        let days_with_input_file = (1..=25).filter(|&day| inputs::has_input(files, day));
 
-        if force_all {
+        if args.force_all {
             days_with_input_file.collect()
         } else {
             days_with_input_file
-                .filter(|day| {
-                    let answer_path = format!("./answers/{:02}.txt", day);
-                    let answer_count = std::fs::read_to_string(answer_path)
-                        .unwrap_or_default()
-                        .lines()
-                        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-                        .count();
-                    answer_count < 2
+                .filter(|&day| {
+                    // Solved once every input has both answers
+                    inputs::load(files, day)
+                        .map_or(true, |inputs| !inputs.iter().all(|input| input.is_solved()))
                 })
                 .collect()
         }
     };
-    days_to_run
+    Ok(days_to_run)
+}
+
+/// Expands the `--day` selection, ignoring whether the days have been solved already.
+fn selected_days(
+    selection: &[std::ops::RangeInclusive<i32>],
+    files: &files::Files,
+    examples: bool,
+) -> anyhow::Result<Vec<i32>> {
+    let mut days = selection
+        .iter()
+        .flat_map(|range| range.clone())
+        .collect::<Vec<_>>();
+    days.sort();
+    days.dedup();
+
+    for &day in days.iter() {
+        if problems::solution(day).is_none() {
+            anyhow::bail!("No problem set mapped for day {}", day);
+        }
+
+        if examples {
+            if !inputs::has_examples(files, day) {
+                anyhow::bail!(
+                    "No examples for day {} in '{}'",
+                    day,
+                    files.inputs_dir(day).display()
+                );
+            }
+            continue;
+        }
+
+        if !inputs::has_input(files, day) {
+            anyhow::bail!(
+                "Missing input file for day {}: '{}'",
+                day,
+                files.input_path(day).display()
+            );
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_day_range(s: &str) -> Result<std::ops::RangeInclusive<i32>, String> {
+    let parse_day = |s: &str| {
+        s.trim()
+            .parse::<i32>()
+            .map_err(|e| format!("Failed to parse day '{}': {}", s, e))
+    };
+
+    let range = if let Some((lo, hi)) = s.split_once("..=") {
+        parse_day(lo)?..=parse_day(hi)?
+    } else if let Some((lo, hi)) = s.split_once("..") {
+        let hi = parse_day(hi)?
+            .checked_sub(1)
+            .ok_or_else(|| format!("Day out of range: '{}'", s))?;
+        parse_day(lo)?..=hi
+    } else {
+        let day = parse_day(s)?;
+        day..=day
+    };
+
+    // Checked before the range is expanded, so a huge range fails right away
+    if range.is_empty() {
+        Err(format!("Empty day range: '{}'", s))
+    } else if *range.start() < 0 || *range.end() > 25 {
+        Err(format!("Days must be between 0 and 25: '{}'", s))
+    } else {
+        Ok(range)
+    }
 }
 
 #[derive(clap::Parser, Debug)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Runs all solutions, even if they have been solved already
     #[arg(short, long = "force-all")]
     force_all: bool,
@@ -171,7 +533,153 @@ struct Args {
     #[arg(short, long)]
     validate: bool,
 
+    /// Directory with the puzzle inputs, parameters and examples
+    #[arg(long = "input-dir", env = "AOC_INPUT_DIR", default_value = "input")]
+    input_dir: std::path::PathBuf,
+
+    /// Directory with the answers to the puzzle inputs and examples
+    #[arg(
+        long = "answers-dir",
+        env = "AOC_ANSWERS_DIR",
+        default_value = "answers"
+    )]
+    answers_dir: std::path::PathBuf,
+
+    /// Reads the input of the selected day from this file instead, or from stdin with `-`
+    #[arg(long, requires = "days", conflicts_with_all = ["validate", "examples"])]
+    input: Option<std::path::PathBuf>,
+
+    /// Runs the examples in /input/NN/ against their answers in /answers/NN/ instead of the puzzle input
+    #[arg(short, long, conflicts_with = "bench")]
+    examples: bool,
+
+    /// Overrides a puzzle parameter, e.g. `steps=6`
+    #[arg(long = "param", value_parser = problems::parse_param)]
+    params: Vec<(String, String)>,
+
     /// Prints solutions to stdout
     #[arg(short, long = "show-solutions")]
     show_solutions: bool,
+
+    /// Runs only the given days, e.g. `5`, `3..=9` or `1,4,17`
+    #[arg(short, long = "day", value_parser = parse_day_range, value_delimiter = ',')]
+    days: Vec<std::ops::RangeInclusive<i32>>,
+
+    /// Runs only the given part of each day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Prints a report of all answers and timings to stdout
+    #[arg(long, value_enum)]
+    format: Option<report::Format>,
+
+    /// Benchmarks each part and reports timing statistics
+    #[arg(short, long)]
+    bench: bool,
+
+    /// Number of untimed warm-up runs per part in bench mode
+    #[arg(long, default_value = "3")]
+    warmup: u32,
+
+    /// Number of timed runs per part in bench mode
+    #[arg(long, default_value = "20", value_parser = clap::value_parser!(u32).range(1..))]
+    samples: u32,
+
+    /// Baseline file that bench mode compares against
+    #[arg(long, default_value = "bench/baseline.json")]
+    baseline: std::path::PathBuf,
+
+    /// Saves the bench results as the new baseline instead of comparing
+    #[arg(long = "save-baseline", requires = "bench")]
+    save_baseline: bool,
+
+    /// Slowdown of the median runtime, in percent, that fails bench mode
+    #[arg(long = "max-regression", default_value = "20", value_parser = baseline::parse_max_regression)]
+    max_regression: f64,
+
+    /// Appends the timestamp, commit, status and runtime of each part to the history file
+    #[arg(long = "record-history", conflicts_with_all = ["bench", "examples", "input", "watch"])]
+    record_history: bool,
+
+    /// History file written by `--record-history` and read by `history`
+    #[arg(long, default_value = "bench/history.jsonl")]
+    history: std::path::PathBuf,
+
+    /// Reruns the selected days whenever their input, examples or answers change
+    #[arg(short, long, requires = "days", conflicts_with_all = ["bench", "input"])]
+    watch: bool,
+
+    /// Stops waiting for a part after the given time, e.g. `30s`, or `23=2m` for a single day
+    #[arg(long = "timeout", value_parser = watchdog::parse_timeout)]
+    timeouts: Vec<watchdog::Timeout>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Creates the module, input, answer and example files of a new day
+    New { day: i32 },
+
+    /// Records a confirmed answer, as a salted hash unless `--plain` is given
+    Record {
+        day: i32,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The answer to record. Runs the solution if omitted
+        answer: Option<String>,
+
+        /// Records the answer of a named input in /input/NN/ instead
+        #[arg(long, default_value = inputs::PUZZLE_INPUT)]
+        name: String,
+
+        /// Stores the answer in plain text
+        #[arg(long)]
+        plain: bool,
+    },
+
+    /// Shows how the runtime of a day's parts changed over the runs recorded with `--record-history`
+    History {
+        day: i32,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+
+    /// Prints a calendar of the status and runtime of each part, from the latest run recorded
+    /// with `--record-history`. Days without one are run
+    Status {
+        /// Runs and validates every day with an input instead of reading the history
+        #[arg(long)]
+        run: bool,
+    },
+}
+
+impl Args {
+    fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Examples are always validated, they are useless otherwise.
+    /// Watch mode validates to show the differences with the expected answers.
+    fn validates(&self) -> bool {
+        self.validate || self.examples || self.watch
+    }
+
+    fn override_params(&self, mut params: Params) -> Params {
+        for (key, value) in self.params.iter() {
+            params.set(key, value);
+        }
+        params
+    }
+
+    fn parts(&self) -> impl Iterator<Item = u8> + '_ {
+        [1, 2].into_iter().filter(|&part| self.runs_part(part))
+    }
+
+    /// Per-day timeouts take precedence over the global one.
+    fn timeout(&self, day: i32) -> Option<std::time::Duration> {
+        let find = |day| self.timeouts.iter().rev().find(|t| t.day == day);
+        find(Some(day)).or_else(|| find(None)).map(|t| t.limit)
+    }
 }
@@ -0,0 +1,63 @@
+/// Value returned by a part, so that placeholders can be told apart from real solutions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(num::BigInt),
+    Str(String),
+    /// The part has not been solved yet.
+    NotImplemented,
+    /// The part has a solution, but it is too slow to run by default.
+    Skipped,
+    /// The answer was found outside of this program and is returned as is.
+    HardCoded(String),
+}
+
+impl Answer {
+    pub fn hard_coded(value: impl ToString) -> Self {
+        Answer::HardCoded(value.to_string())
+    }
+
+    /// The answer to compare against `answers/NN.txt`, `None` for placeholders.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            Answer::NotImplemented | Answer::Skipped => None,
+            answer => Some(answer.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => value.fmt(f),
+            Answer::BigInt(value) => value.fmt(f),
+            Answer::Str(value) | Answer::HardCoded(value) => value.fmt(f),
+            Answer::NotImplemented => f.pad("not implemented"),
+            Answer::Skipped => f.pad("skipped"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                i64::try_from(value).map_or_else(|_| Answer::BigInt(value.into()), Answer::Int)
+            }
+        })*
+    };
+}
+
+from_int!(i32, i64, i128, u32, u64, u128, usize);
+
+impl From<num::BigInt> for Answer {
+    fn from(value: num::BigInt) -> Self {
+        Answer::BigInt(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
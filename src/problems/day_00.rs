@@ -1,7 +1,17 @@
-pub fn part_one(_input: &str) -> anyhow::Result<String> {
-    Ok("not implemented".to_string())
-}
+pub struct Day00;
+
+impl super::Solution for Day00 {
+    type Input = ();
+
+    fn parse(_input: &str) -> anyhow::Result<Self::Input> {
+        Ok(())
+    }
+
+    fn part_one(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(super::Answer::NotImplemented)
+    }
 
-pub fn part_two(_input: &str) -> anyhow::Result<String> {
-    Ok("not implemented".to_string())
+    fn part_two(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(super::Answer::NotImplemented)
+    }
 }
@@ -1,46 +1,56 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(solve(
-        input,
-        vec![
-            ("1", 1),
-            ("2", 2),
-            ("3", 3),
-            ("4", 4),
-            ("5", 5),
-            ("6", 6),
-            ("7", 7),
-            ("8", 8),
-            ("9", 9),
-        ],
-    )?
-    .to_string())
-}
+pub struct Day01;
+
+impl super::Solution for Day01 {
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.into())
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(
+            input,
+            vec![
+                ("1", 1),
+                ("2", 2),
+                ("3", 3),
+                ("4", 4),
+                ("5", 5),
+                ("6", 6),
+                ("7", 7),
+                ("8", 8),
+                ("9", 9),
+            ],
+        )?
+        .into())
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok(solve(
-        input,
-        vec![
-            ("1", 1),
-            ("one", 1),
-            ("2", 2),
-            ("two", 2),
-            ("3", 3),
-            ("three", 3),
-            ("4", 4),
-            ("four", 4),
-            ("5", 5),
-            ("five", 5),
-            ("6", 6),
-            ("six", 6),
-            ("7", 7),
-            ("seven", 7),
-            ("8", 8),
-            ("eight", 8),
-            ("9", 9),
-            ("nine", 9),
-        ],
-    )?
-    .to_string())
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(
+            input,
+            vec![
+                ("1", 1),
+                ("one", 1),
+                ("2", 2),
+                ("two", 2),
+                ("3", 3),
+                ("three", 3),
+                ("4", 4),
+                ("four", 4),
+                ("5", 5),
+                ("five", 5),
+                ("6", 6),
+                ("six", 6),
+                ("7", 7),
+                ("seven", 7),
+                ("8", 8),
+                ("eight", 8),
+                ("9", 9),
+                ("nine", 9),
+            ],
+        )?
+        .into())
+    }
 }
 
 fn solve(input: &str, patterns: Vec<(&str, i32)>) -> anyhow::Result<i32> {
@@ -1,27 +1,43 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(parse_input(input)?
-        .iter()
-        .filter(|game| {
-            game.subsets
-                .iter()
-                .all(|subset| subset.red <= 12 && subset.green <= 13 && subset.blue <= 14)
-        })
-        .map(|game| game.id)
-        .sum::<u32>()
-        .to_string())
-}
+pub struct Day02;
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok(parse_input(input)?
-        .iter()
-        .map(|game| {
-            game.subsets
-                .iter()
-                .fold(CubeSubset::EMPTY, |acc, subset| acc.max(subset))
-                .power()
-        })
-        .sum::<u32>()
-        .to_string())
+impl super::Solution for Day02 {
+    type Input = Vec<GameRecord>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        let bag = CubeSubset {
+            red: params.get("red", 12)?,
+            green: params.get("green", 13)?,
+            blue: params.get("blue", 14)?,
+        };
+
+        Ok(input
+            .iter()
+            .filter(|game| {
+                game.subsets.iter().all(|subset| {
+                    subset.red <= bag.red && subset.green <= bag.green && subset.blue <= bag.blue
+                })
+            })
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(input
+            .iter()
+            .map(|game| {
+                game.subsets
+                    .iter()
+                    .fold(CubeSubset::EMPTY, |acc, subset| acc.max(subset))
+                    .power()
+            })
+            .sum::<u32>()
+            .into())
+    }
 }
 
 fn parse_input(input: &str) -> anyhow::Result<Vec<GameRecord>> {
@@ -68,7 +84,7 @@ fn parse_subset(subset: &str) -> Result<CubeSubset, anyhow::Error> {
         })
 }
 
-struct GameRecord {
+pub struct GameRecord {
     id: u32,
     subsets: Vec<CubeSubset>,
 }
@@ -1,135 +1,116 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let lines = parse_input(input)?;
-    Ok(lines
-        .iter()
-        .enumerate()
-        .flat_map(|(i, line)| {
-            let line_lo = i.saturating_sub(1);
-            let line_hi = (i + 1).min(lines.len() - 1);
+use crate::util::grid::{Grid, Pos};
 
-            line.numbers
-                .iter()
-                .filter(|number| {
-                    let col_lo = number.start.saturating_sub(1);
-                    let col_hi = number.end + 1;
+pub struct Day03;
 
-                    lines[line_lo..=line_hi].iter().any(|line| {
-                        line.symbols
-                            .iter()
-                            .any(|symbol| (col_lo..=col_hi).contains(&symbol.pos))
-                    })
-                })
-                .map(|number| number.number)
-                .collect::<Vec<_>>()
-        })
-        .sum::<i32>()
-        .to_string())
-}
+impl super::Solution for Day03 {
+    type Input = Schematic;
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let lines = parse_input(input)?;
-    Ok(lines
-        .iter()
-        .enumerate()
-        .flat_map(|(i, line)| {
-            line.symbols
-                .iter()
-                .filter_map(|symbol| {
-                    if symbol.kind == b'*' {
-                        let line_lo = i.saturating_sub(1);
-                        let line_hi = i + 1;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
 
-                        let numbers_around = lines[line_lo..=line_hi]
-                            .iter()
-                            .flat_map(|line| {
-                                line.numbers.iter().filter_map(|number| {
-                                    let col_lo = number.start.saturating_sub(1);
-                                    let col_hi = number.end + 1;
-                                    (col_lo..=col_hi)
-                                        .contains(&symbol.pos)
-                                        .then_some(number.number)
-                                })
-                            })
-                            .collect::<Vec<_>>();
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let schematic = input;
+        Ok(schematic
+            .numbers
+            .iter()
+            .filter(|number| {
+                number.positions().any(|pos| {
+                    schematic
+                        .grid
+                        .neighbours8(pos)
+                        .any(|n| is_symbol(schematic.grid[n]))
+                })
+            })
+            .map(|number| number.number)
+            .sum::<i32>()
+            .into())
+    }
 
-                        (numbers_around.len() == 2)
-                            .then_some(numbers_around.iter().product::<i32>())
-                    } else {
-                        None
-                    }
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let schematic = input;
+        Ok(schematic
+            .grid
+            .enumerate()
+            .filter(|(_, &c)| c == b'*')
+            .filter_map(|(pos, _)| {
+                let mut numbers_around = schematic
+                    .grid
+                    .neighbours8(pos)
+                    .filter_map(|n| schematic.number_ids[n])
+                    .collect::<Vec<_>>();
+                numbers_around.sort();
+                numbers_around.dedup();
+
+                (numbers_around.len() == 2).then(|| {
+                    numbers_around
+                        .iter()
+                        .map(|&id| schematic.numbers[id].number)
+                        .product::<i32>()
                 })
-                .collect::<Vec<_>>()
-        })
-        .sum::<i32>()
-        .to_string())
+            })
+            .sum::<i32>()
+            .into())
+    }
 }
 
-fn parse_input(input: &str) -> anyhow::Result<Vec<SchematicLine>> {
-    input.lines().map(parse_line).collect()
+fn is_symbol(c: u8) -> bool {
+    !c.is_ascii_digit() && c != b'.'
 }
 
-fn parse_line(line: &str) -> anyhow::Result<SchematicLine> {
-    let mut digits = Vec::with_capacity(4);
-    let mut symbols = Vec::with_capacity(16);
-    let mut numbers = Vec::with_capacity(16);
-
-    line.as_bytes().iter().enumerate().try_for_each(|(i, &c)| {
-        let is_digit = c.is_ascii_digit();
-        let is_symbol = !is_digit && c != b'.';
-
-        let is_whitespace = !is_digit && !is_symbol;
-
-        if is_digit {
-            // append digit to number
-            match char::to_digit(c as char, 10) {
-                Some(digit) => {
-                    digits.push(digit);
-                }
-                None => anyhow::bail!("Failed to parse digit: '{}'", c),
+fn parse_input(input: &str) -> anyhow::Result<Schematic> {
+    let grid = Grid::parse(input)?;
+    let mut number_ids = Grid::new(grid.width(), grid.height(), None);
+    let mut numbers = Vec::with_capacity(1024);
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
             }
-        }
-
-        if is_symbol {
-            // append symbol to symbol span
-            symbols.push(Symbol { kind: c, pos: i });
-        }
 
-        if (is_whitespace || is_symbol) && !digits.is_empty() {
-            // commit number span
-            let number = digits.iter().fold(0, |acc, &digit| acc * 10 + digit as i32);
-            let start = i - digits.len();
-            let end = i - 1;
+            let start = x;
+            let mut number = 0;
+            while x < row.len() && row[x].is_ascii_digit() {
+                number = number * 10 + (row[x] - b'0') as i32;
+                number_ids[(x, y)] = Some(numbers.len());
+                x += 1;
+            }
 
-            numbers.push(NumberSpan { number, start, end });
-            digits.clear();
+            numbers.push(NumberSpan {
+                number,
+                y,
+                start,
+                end: x - 1,
+            });
         }
-
-        Ok(())
-    })?;
-
-    // append trailing number span
-    if !digits.is_empty() {
-        let number = digits.iter().fold(0, |acc, &digit| acc * 10 + digit as i32);
-        let start = line.len() - digits.len();
-        let end = line.len() - 1;
-        numbers.push(NumberSpan { number, start, end });
     }
 
-    Ok(SchematicLine { numbers, symbols })
+    Ok(Schematic {
+        grid,
+        numbers,
+        number_ids,
+    })
 }
 
-struct SchematicLine {
+pub struct Schematic {
+    grid: Grid<u8>,
     numbers: Vec<NumberSpan>,
-    symbols: Vec<Symbol>,
+    /// Index into `numbers` of the number each digit belongs to.
+    number_ids: Grid<Option<usize>>,
 }
 
 struct NumberSpan {
     number: i32,
+    y: usize,
     start: usize,
     end: usize,
 }
 
-struct Symbol {
-    kind: u8,
-    pos: usize,
+impl NumberSpan {
+    fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (self.start..=self.end).map(|x| (x, self.y))
+    }
 }
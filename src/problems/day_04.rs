@@ -1,36 +1,46 @@
 use crate::util::parse_space_separated_numbers;
 
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(parse_input(input)?
-        .iter()
-        .map(|card| card.points())
-        .sum::<u32>()
-        .to_string())
-}
+pub struct Day04;
+
+impl super::Solution for Day04 {
+    type Input = Vec<ScratchCard>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(input
+            .iter()
+            .map(|card| card.points())
+            .sum::<u32>()
+            .into())
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let cards = parse_input(input)?;
-
-    let mut copies = vec![1; cards.len()];
-
-    Ok(cards
-        .iter()
-        .enumerate()
-        .fold(0, |card_count, (i, card)| {
-            let instances = copies[i];
-
-            let points = card.matching_numbers();
-            for copies in copies
-                .iter_mut()
-                .take((i + points as usize).min(cards.len() - 1) + 1)
-                .skip(i + 1)
-            {
-                *copies += instances;
-            }
-
-            card_count + instances
-        })
-        .to_string())
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let cards = input;
+
+        let mut copies = vec![1; cards.len()];
+
+        Ok(cards
+            .iter()
+            .enumerate()
+            .fold(0, |card_count, (i, card)| {
+                let instances = copies[i];
+
+                let points = card.matching_numbers();
+                for copies in copies
+                    .iter_mut()
+                    .take((i + points as usize).min(cards.len() - 1) + 1)
+                    .skip(i + 1)
+                {
+                    *copies += instances;
+                }
+
+                card_count + instances
+            })
+            .into())
+    }
 }
 
 fn parse_input(input: &str) -> anyhow::Result<Vec<ScratchCard>> {
@@ -55,7 +65,7 @@ fn parse_line(line: &str) -> anyhow::Result<ScratchCard> {
     })
 }
 
-struct ScratchCard {
+pub struct ScratchCard {
     winning_numbers: Vec<u32>,
     numbers: Vec<u32>,
 }
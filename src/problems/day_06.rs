@@ -1,59 +1,69 @@
 use crate::util::{parse_space_separated_numbers, solve_quadratic_equation, QuadraticSolution};
 
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let (times, distances) = input
-        .split_once('\n')
-        .ok_or_else(|| anyhow::anyhow!("Failed to split input lines"))?;
-
-    let times = parse_space_separated_numbers::<u64>(
-        times
-            .strip_prefix("Time: ")
-            .ok_or_else(|| anyhow::anyhow!("Failed to strip time prefix"))?,
-    )?;
-    let distances = parse_space_separated_numbers::<u64>(
-        distances
-            .strip_prefix("Distance: ")
-            .ok_or_else(|| anyhow::anyhow!("Failed to strip distance prefix"))?,
-    )?;
-
-    Ok(times
-        .iter()
-        .zip(distances)
-        .map(|(&time, distance)| number_of_ways(time, distance))
-        .collect::<anyhow::Result<Vec<u64>>>()?
-        .iter()
-        .product::<u64>()
-        .to_string())
+pub struct Day06;
+
+impl super::Solution for Day06 {
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let (times, distances) = input
+            .split_once('\n')
+            .ok_or_else(|| anyhow::anyhow!("Failed to split input lines"))?;
+
+        let times = parse_space_separated_numbers::<u64>(
+            times
+                .strip_prefix("Time: ")
+                .ok_or_else(|| anyhow::anyhow!("Failed to strip time prefix"))?,
+        )?;
+        let distances = parse_space_separated_numbers::<u64>(
+            distances
+                .strip_prefix("Distance: ")
+                .ok_or_else(|| anyhow::anyhow!("Failed to strip distance prefix"))?,
+        )?;
+
+        Ok((times, distances))
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let (times, distances) = input;
+
+        Ok(times
+            .iter()
+            .zip(distances)
+            .map(|(&time, &distance)| number_of_ways(time, distance))
+            .collect::<anyhow::Result<Vec<u64>>>()?
+            .iter()
+            .product::<u64>()
+            .into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let (times, distances) = input;
+
+        // The numbers were never separate, the spaces are just bad kerning
+        let time = concat_digits(times)?;
+        let distance = concat_digits(distances)?;
+
+        Ok(number_of_ways(time, distance)?.into())
+    }
 }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let (time, distance) = input
-        .split_once('\n')
-        .ok_or_else(|| anyhow::anyhow!("Failed to split input lines"))?;
-
-    let time = time
-        .strip_prefix("Time: ")
-        .ok_or_else(|| anyhow::anyhow!("Failed to strip time prefix"))?
-        .replace(' ', "")
-        .trim()
-        .parse::<u64>()?;
-
-    let distance = distance
-        .strip_prefix("Distance: ")
-        .ok_or_else(|| anyhow::anyhow!("Failed to strip distance prefix"))?
-        .replace(' ', "")
-        .trim()
-        .parse::<u64>()?;
-
-    Ok(number_of_ways(time, distance)?.to_string())
+fn concat_digits(numbers: &[u64]) -> anyhow::Result<u64> {
+    Ok(numbers
+        .iter()
+        .map(u64::to_string)
+        .collect::<String>()
+        .parse::<u64>()?)
 }
 
 fn number_of_ways(total_time: u64, distance_to_beat: u64) -> anyhow::Result<u64> {
+    // The roots only tie the record, so the first and last winning times are strictly inside
     let (lo, hi) =
         match solve_quadratic_equation(-1.0, total_time as f64, -(distance_to_beat as f64)) {
             QuadraticSolution::None => anyhow::bail!("Failed to find bounds"),
-            QuadraticSolution::OneRoot(m) => (m.ceil() as u64, m.floor() as u64),
-            QuadraticSolution::TwoRoots(lo, hi) => (lo.ceil() as u64, hi.floor() as u64),
+            QuadraticSolution::OneRoot(m) => (m, m),
+            QuadraticSolution::TwoRoots(lo, hi) => (lo, hi),
         };
-    Ok(hi - lo + 1)
+    let (lo, hi) = (lo.floor() as u64 + 1, hi.ceil() as u64 - 1);
+    Ok((hi + 1).saturating_sub(lo))
 }
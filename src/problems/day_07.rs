@@ -1,13 +1,26 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    solve(input, false)
-}
+pub struct Day07;
+
+impl super::Solution for Day07 {
+    type Input = Vec<(Vec<Card>, i32)>;
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    solve(input, true)
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        solve(input, false)
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        solve(input, true)
+    }
 }
 
-fn solve(input: &str, jokers: bool) -> anyhow::Result<String> {
-    let mut data = parse_input(input, jokers)?;
+fn solve(hands: &[(Vec<Card>, i32)], jokers: bool) -> anyhow::Result<super::Answer> {
+    let mut data = hands
+        .iter()
+        .map(|(cards, bid)| (Hand::new(cards, jokers), *bid))
+        .collect::<Vec<_>>();
     data.sort_by_key(|(hand, _)| hand.sortable_hash);
 
     Ok(data
@@ -18,10 +31,10 @@ fn solve(input: &str, jokers: bool) -> anyhow::Result<String> {
             bid * rank
         })
         .sum::<i32>()
-        .to_string())
+        .into())
 }
 
-fn parse_input(input: &str, jokers: bool) -> anyhow::Result<Vec<(Hand, i32)>> {
+fn parse_input(input: &str) -> anyhow::Result<Vec<(Vec<Card>, i32)>> {
     input
         .lines()
         .map(|line| {
@@ -29,12 +42,12 @@ fn parse_input(input: &str, jokers: bool) -> anyhow::Result<Vec<(Hand, i32)>> {
                 .split_once(' ')
                 .ok_or_else(|| anyhow::anyhow!("Failed to split line: '{}'", line))?;
 
-            let hand = Hand::from(cards, jokers);
+            let cards = cards.as_bytes().iter().map(Card::new).collect();
             let bid = bid.parse::<i32>()?;
 
-            Ok((hand, bid))
+            Ok((cards, bid))
         })
-        .collect::<anyhow::Result<Vec<(Hand, i32)>>>()
+        .collect::<anyhow::Result<Vec<(Vec<Card>, i32)>>>()
 }
 
 struct Hand {
@@ -42,9 +55,9 @@ struct Hand {
 }
 
 impl Hand {
-    fn new(cards: Vec<Card>, jokers: bool) -> Self {
+    fn new(cards: &[Card], jokers: bool) -> Self {
         let sortable_hash = {
-            let kind = Self::kind(&cards, jokers) as i32;
+            let kind = Self::kind(cards, jokers) as i32;
             cards.iter().fold(kind, |key, card| {
                 (key << 4)
                     + if jokers && card.strength == Self::JOKER_STRENGTH {
@@ -95,11 +108,6 @@ impl Hand {
             _ => unreachable!(),
         }
     }
-
-    fn from(cards: &str, jokers: bool) -> Self {
-        let cards = cards.as_bytes().iter().map(Card::new).collect::<Vec<_>>();
-        Self::new(cards, jokers)
-    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Ord, PartialOrd)]
@@ -114,7 +122,7 @@ enum HandKind {
 }
 
 #[derive(Clone, Copy)]
-struct Card {
+pub struct Card {
     strength: i32,
 }
 
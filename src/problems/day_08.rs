@@ -1,23 +1,33 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let map = parse_input(input)?;
-    Ok(find_exit(&map, node_id_hash("AAA")).to_string())
-}
+pub struct Day08;
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let map = parse_input(input)?;
-    Ok(map
-        .connections
-        .iter()
-        .enumerate()
-        .filter_map(|(from, &(left, right))| {
-            if left != 0 && right != 0 && (from & 0b11111) == 0 {
-                Some(find_exit(&map, from))
-            } else {
-                None
-            }
-        })
-        .fold(1, |a, b| num::Integer::lcm(&a, &b))
-        .to_string())
+impl super::Solution for Day08 {
+    type Input = Map;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let map = input;
+        Ok(find_exit(map, node_id_hash("AAA")).into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let map = input;
+        Ok(map
+            .connections
+            .iter()
+            .enumerate()
+            .filter_map(|(from, &(left, right))| {
+                if left != 0 && right != 0 && (from & 0b11111) == 0 {
+                    Some(find_exit(map, from))
+                } else {
+                    None
+                }
+            })
+            .fold(1, |a, b| num::Integer::lcm(&a, &b))
+            .into())
+    }
 }
 
 fn find_exit(map: &Map, mut from_node: usize) -> i64 {
@@ -74,7 +84,7 @@ fn node_id_hash(node_id: &str) -> usize {
 }
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     instructions: Vec<u8>,
     connections: Vec<(usize, usize)>,
 }
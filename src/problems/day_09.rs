@@ -1,17 +1,27 @@
 use crate::util::parse_space_separated_numbers;
 
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(solve(input, &extrapolate_forward)?.to_string())
-}
+pub struct Day09;
+
+impl super::Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok(solve(input, &extrapolate_backward)?.to_string())
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, &extrapolate_forward)?.into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, &extrapolate_backward)?.into())
+    }
 }
 
-fn solve(input: &str, extrapolate: &impl Fn(&[Vec<i32>]) -> i32) -> anyhow::Result<i32> {
-    Ok(parse_input(input)?
-        .into_iter()
-        .map(history)
+fn solve(input: &[Vec<i32>], extrapolate: &impl Fn(&[Vec<i32>]) -> i32) -> anyhow::Result<i32> {
+    Ok(input
+        .iter()
+        .map(|seq| history(seq.clone()))
         .collect::<anyhow::Result<Vec<_>>>()?
         .iter_mut()
         .map(|hist| {
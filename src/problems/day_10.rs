@@ -1,42 +1,55 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let mut map = parse_input(input)?;
-    let tile_loop = find_loop(&mut map);
-    Ok((tile_loop.len() / 2).to_string())
-}
+use crate::util::geom::Dir4;
+use crate::util::grid::{Grid, Pos};
+
+pub struct Day10;
+
+impl super::Solution for Day10 {
+    type Input = PipeMap;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let mut map = parse_input(input)?;
-    find_loop(&mut map);
-
-    let mut inside = 0;
-    for row in map.tiles.iter_mut() {
-        let mut count = 0;
-        for tile in row.iter_mut() {
-            if tile.visited {
-                if matches!(tile.kind, b'|' | b'L' | b'J') {
-                    count += 1;
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let mut map = input.clone();
+        let tile_loop = find_loop(&mut map);
+        Ok((tile_loop.len() / 2).into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let mut map = input.clone();
+        find_loop(&mut map);
+
+        let mut inside = 0;
+        for row in map.tiles.rows() {
+            let mut count = 0;
+            for tile in row.iter() {
+                if tile.visited {
+                    if matches!(tile.kind, b'|' | b'L' | b'J') {
+                        count += 1;
+                    }
+                } else if count % 2 == 1 {
+                    inside += 1;
                 }
-            } else if count % 2 == 1 {
-                inside += 1;
             }
         }
-    }
 
-    Ok(inside.to_string())
+        Ok(inside.into())
+    }
 }
 
 fn find_loop(map: &mut PipeMap) -> Vec<Tile> {
     let mut vertices = Vec::with_capacity(8096);
-    let (mut x, mut y) = map.start;
+    let mut pos = map.start;
 
     loop {
-        let current = &mut map.tiles[y][x];
+        let current = &mut map.tiles[pos];
         current.visited = true;
         vertices.push(current.clone());
 
-        match unvisited_neighbor(map, (x, y)) {
+        match unvisited_neighbor(map, pos) {
             Some(next_tile) => {
-                (x, y) = next_tile.pos;
+                pos = next_tile.pos;
             }
             None => break,
         }
@@ -45,96 +58,58 @@ fn find_loop(map: &mut PipeMap) -> Vec<Tile> {
     vertices
 }
 
-fn unvisited_neighbor(map: &PipeMap, (x, y): (usize, usize)) -> Option<&Tile> {
-    let current = &map.tiles[y][x];
-
-    if y > 0 && current.connects_top() {
-        let top = &map.tiles[y - 1][x];
-        if !top.visited && top.connects_bottom() {
-            return Some(top);
-        }
-    }
-
-    if x < map.tiles[0].len() - 1 && current.connects_right() {
-        let right = &map.tiles[y][x + 1];
-        if !right.visited && right.connects_left() {
-            return Some(right);
-        }
-    }
-
-    if y < map.tiles.len() - 1 && current.connects_bottom() {
-        let bottom = &map.tiles[y + 1][x];
-        if !bottom.visited && bottom.connects_top() {
-            return Some(bottom);
-        }
-    }
-
-    if x > 0 && current.connects_left() {
-        let left = &map.tiles[y][x - 1];
-        if !left.visited && left.connects_right() {
-            return Some(left);
-        }
-    }
+fn unvisited_neighbor(map: &PipeMap, pos: Pos) -> Option<&Tile> {
+    let current = &map.tiles[pos];
 
-    None
+    Dir4::ALL
+        .into_iter()
+        .filter(|&dir| current.connects(dir))
+        .find_map(|dir| {
+            let neighbor = &map.tiles[map.tiles.neighbour(pos, dir)?];
+            (!neighbor.visited && neighbor.connects(dir.reverse())).then_some(neighbor)
+        })
 }
 
 fn parse_input(input: &str) -> anyhow::Result<PipeMap> {
     let mut start = None;
 
-    let map = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .map(|(x, &c)| {
-                    if c == b'S' {
-                        start = Some((x, y));
-                    }
-                    Tile {
-                        pos: (x, y),
-                        kind: c,
-                        visited: false,
-                    }
-                })
-                .collect::<Vec<_>>()
+    let tiles = Grid::parse_with(input, |c, pos| {
+        if c == b'S' {
+            start = Some(pos);
+        }
+        Ok(Tile {
+            pos,
+            kind: c,
+            visited: false,
         })
-        .collect::<Vec<_>>();
+    })?;
 
     Ok(PipeMap {
-        tiles: map,
+        tiles,
         start: start.ok_or_else(|| anyhow::anyhow!("Failed to find start tile"))?,
     })
 }
 
-struct PipeMap {
-    tiles: Vec<Vec<Tile>>,
-    start: (usize, usize),
+#[derive(Clone)]
+pub struct PipeMap {
+    tiles: Grid<Tile>,
+    start: Pos,
 }
 
 #[derive(Clone)]
 struct Tile {
-    pos: (usize, usize),
+    pos: Pos,
     kind: u8,
     visited: bool,
 }
 
 impl Tile {
-    fn connects_top(&self) -> bool {
-        matches!(self.kind, b'|' | b'L' | b'J' | b'S')
-    }
-
-    fn connects_bottom(&self) -> bool {
-        matches!(self.kind, b'|' | b'F' | b'7' | b'S')
-    }
-
-    fn connects_left(&self) -> bool {
-        matches!(self.kind, b'-' | b'J' | b'7' | b'S')
-    }
-
-    fn connects_right(&self) -> bool {
-        matches!(self.kind, b'-' | b'L' | b'F' | b'S')
+    fn connects(&self, dir: Dir4) -> bool {
+        match dir {
+            Dir4::Up => matches!(self.kind, b'|' | b'L' | b'J' | b'S'),
+            Dir4::Down => matches!(self.kind, b'|' | b'F' | b'7' | b'S'),
+            Dir4::Left => matches!(self.kind, b'-' | b'J' | b'7' | b'S'),
+            Dir4::Right => matches!(self.kind, b'-' | b'L' | b'F' | b'S'),
+        }
     }
 }
@@ -1,12 +1,24 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(solve(parse_input(input), 2).to_string())
-}
+use crate::util::grid::Grid;
+
+pub struct Day11;
+
+impl super::Solution for Day11 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Grid::parse_with(input, |cell, _| Ok(cell == b'#'))
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok(solve(parse_input(input), 1_000_000).to_string())
+    fn part_one(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, params.get("expansion", 2)?).into())
+    }
+
+    fn part_two(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, params.get("expansion", 1_000_000)?).into())
+    }
 }
 
-fn solve(map: Vec<Vec<Option<(i64, i64)>>>, expansion_factor: i64) -> i64 {
+fn solve(map: &Grid<bool>, expansion_factor: i64) -> i64 {
     let galaxies = flat_expand(map, expansion_factor);
     galaxies
         .iter()
@@ -20,27 +32,20 @@ fn solve(map: Vec<Vec<Option<(i64, i64)>>>, expansion_factor: i64) -> i64 {
         .sum()
 }
 
-fn flat_expand(map: Vec<Vec<Option<(i64, i64)>>>, expansion_factor: i64) -> Vec<(i64, i64)> {
-    let mut col_empty = [true; 140];
-    let mut row_empty = [true; 140];
-
-    for row in map.iter() {
-        for cell in row.iter() {
-            if let &Some((x, y)) = cell {
-                col_empty[x as usize] = false;
-                row_empty[y as usize] = false;
-            }
-        }
-    }
+fn flat_expand(map: &Grid<bool>, expansion_factor: i64) -> Vec<(i64, i64)> {
+    let col_empty = map
+        .columns()
+        .map(|mut column| !column.any(|&galaxy| galaxy))
+        .collect::<Vec<_>>();
 
     let mut galaxies = Vec::with_capacity(512);
 
     let mut y_offset = 0;
-    for (y, row) in map.iter().enumerate() {
+    for (y, row) in map.rows().enumerate() {
         let mut x_offset = 0;
-        for (x, col) in row.iter().enumerate() {
-            if let Some((x, y)) = col {
-                galaxies.push((x + x_offset, y + y_offset));
+        for (x, &galaxy) in row.iter().enumerate() {
+            if galaxy {
+                galaxies.push((x as i64 + x_offset, y as i64 + y_offset));
             }
 
             if col_empty[x] {
@@ -48,32 +53,10 @@ fn flat_expand(map: Vec<Vec<Option<(i64, i64)>>>, expansion_factor: i64) -> Vec<
             }
         }
 
-        if row_empty[y] {
+        if !row.contains(&true) {
             y_offset += expansion_factor - 1;
         }
     }
 
     galaxies
 }
-
-fn parse_input(input: &str) -> Vec<Vec<Option<(i64, i64)>>> {
-    let mut id = 0;
-    input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .map(|(x, &cell)| {
-                    if cell == b'#' {
-                        id += 1;
-                        Some((x as i64, y as i64))
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        })
-        .collect()
-}
@@ -1,29 +1,39 @@
-use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let sum = parse_input(input)?
-        .into_iter()
-        .map(|(pattern, groups)| arrangements(pattern, groups))
-        .sum::<usize>();
-    Ok(sum.to_string())
-}
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+
+pub struct Day12;
+
+impl super::Solution for Day12 {
+    type Input = Vec<(Vec<u8>, Vec<usize>)>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let n = 5;
-    let sum = parse_input(input)?
-        .into_par_iter()
-        .map(|(pattern, groups)| {
-            let mut repeated_pattern = Vec::with_capacity(pattern.len() * n + n);
-            for i in 0..n {
-                if i > 0 {
-                    repeated_pattern.push(b'?');
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let sum = input
+            .iter()
+            .map(|(pattern, groups)| arrangements(pattern.clone(), groups.clone()))
+            .sum::<usize>();
+        Ok(sum.into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let n = 5;
+        let sum = input
+            .par_iter()
+            .map(|(pattern, groups)| {
+                let mut repeated_pattern = Vec::with_capacity(pattern.len() * n + n);
+                for i in 0..n {
+                    if i > 0 {
+                        repeated_pattern.push(b'?');
+                    }
+                    repeated_pattern.extend_from_slice(pattern);
                 }
-                repeated_pattern.extend_from_slice(&pattern);
-            }
-            arrangements(repeated_pattern, groups.repeat(n))
-        })
-        .sum::<usize>();
-    Ok(sum.to_string())
+                arrangements(repeated_pattern, groups.repeat(n))
+            })
+            .sum::<usize>();
+        Ok(sum.into())
+    }
 }
 
 fn arrangements(mut pattern: Vec<u8>, groups: Vec<usize>) -> usize {
@@ -1,12 +1,24 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(solve(parse_input(input)?, 0)?.to_string())
-}
+use crate::util::grid::Grid;
+
+pub struct Day13;
+
+impl super::Solution for Day13 {
+    type Input = Vec<Grid<u8>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, 0)?.into())
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok(solve(parse_input(input)?, 1)?.to_string())
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, 1)?.into())
+    }
 }
 
-fn solve(patterns: Vec<Vec<Vec<u8>>>, required_differences: usize) -> anyhow::Result<usize> {
+fn solve(patterns: &[Grid<u8>], required_differences: usize) -> anyhow::Result<usize> {
     Ok(patterns
         .iter()
         .map(|pattern| {
@@ -22,33 +34,23 @@ fn solve(patterns: Vec<Vec<Vec<u8>>>, required_differences: usize) -> anyhow::Re
         .sum::<usize>())
 }
 
-fn transpose(pattern: &[Vec<u8>]) -> Vec<Vec<u8>> {
-    let mut transposed = vec![Vec::with_capacity(pattern.len()); pattern[0].len()];
-    for s in pattern {
-        for (i, &c) in s.iter().enumerate() {
-            transposed[i].push(c);
-        }
-    }
-    transposed
-}
-
-fn find_reflection(pattern: &[Vec<u8>], required_differences: usize) -> Option<Reflection> {
+fn find_reflection(pattern: &Grid<u8>, required_differences: usize) -> Option<Reflection> {
     find_horizontal_reflection(pattern, required_differences).or_else(|| {
-        let transposed = transpose(pattern);
+        let transposed = pattern.transpose();
         find_horizontal_reflection(&transposed, required_differences).map(|r| r.into_rotated())
     })
 }
 
 fn find_horizontal_reflection(
-    pattern: &[Vec<u8>],
+    pattern: &Grid<u8>,
     required_differences: usize,
 ) -> Option<Reflection> {
-    for mirror_y in 1..pattern.len() {
-        let height = mirror_y.min(pattern.len() - mirror_y);
+    for mirror_y in 1..pattern.height() {
+        let height = mirror_y.min(pattern.height() - mirror_y);
         let mut differences = 0;
         for offset_y in 0..height {
-            let a = &pattern[mirror_y + offset_y];
-            let b = &pattern[mirror_y - offset_y - 1];
+            let a = pattern.row(mirror_y + offset_y);
+            let b = pattern.row(mirror_y - offset_y - 1);
 
             for (a, b) in a.iter().zip(b) {
                 if a != b {
@@ -87,17 +89,10 @@ impl Reflection {
     }
 }
 
-fn parse_input(input: &str) -> anyhow::Result<Vec<Vec<Vec<u8>>>> {
-    input.lines().try_fold(vec![vec![]], |mut acc, line| {
-        if line.is_empty() {
-            acc.push(vec![]);
-            Ok(acc)
-        } else {
-            let last = acc
-                .last_mut()
-                .ok_or_else(|| anyhow::anyhow!("Failed to get last row in acc"))?;
-            last.push(line.as_bytes().to_vec());
-            Ok(acc)
-        }
-    })
+fn parse_input(input: &str) -> anyhow::Result<Vec<Grid<u8>>> {
+    input
+        .split("\n\n")
+        .filter(|pattern| !pattern.trim().is_empty())
+        .map(Grid::parse)
+        .collect()
 }
@@ -1,122 +1,84 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let mut dish = parse_input(input);
-    slide_dish_north_in_place(&mut dish);
-    Ok(north_beam_load(dish).to_string())
-}
-
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let mut dish = parse_input(input);
-
-    let mut visited = hashbrown::HashMap::with_capacity(1024);
-    visited.insert(dish.clone(), 0);
+use crate::util::cycle;
+use crate::util::geom::Dir4;
+use crate::util::grid::Grid;
 
-    let test_cycles = 1_000_000_000;
+pub struct Day14;
 
-    let (cycle_start, cycle_end) = loop {
-        slide_dish_north_in_place(&mut dish);
-        slide_dish_west_in_place(&mut dish);
-        slide_dish_south_in_place(&mut dish);
-        slide_dish_east_in_place(&mut dish);
+impl super::Solution for Day14 {
+    type Input = Grid<u8>;
 
-        if let Some(prev) = visited.insert(dish.clone(), visited.len()) {
-            break (prev, visited.len());
-        }
-    };
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Grid::parse(input)
+    }
 
-    let cycle_offset = test_cycles - cycle_start;
-    let cycle_len = cycle_end - cycle_start;
-    let rem = cycle_offset % cycle_len;
-    let target = cycle_start + rem;
-    let (dish, _) = visited
-        .iter()
-        .find(|(_, &i)| i == target)
-        .ok_or_else(|| anyhow::anyhow!("Failed to find target dish"))?;
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let mut dish = input.clone();
+        slide_dish_in_place(&mut dish, Dir4::Up);
+        Ok(north_beam_load(&dish).into())
+    }
 
-    Ok(north_beam_load(dish.clone()).to_string())
+    fn part_two(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        let test_cycles = params.get("cycles", 1_000_000_000)?;
+        let spins = cycle::unroll(input.clone(), |dish| {
+            let mut dish = dish.clone();
+            spin_cycle(&mut dish);
+            dish
+        });
+        Ok(north_beam_load(spins.nth(test_cycles)).into())
+    }
 }
 
-fn north_beam_load(dish: Vec<Vec<u8>>) -> usize {
-    dish.iter()
+fn north_beam_load(dish: &Grid<u8>) -> usize {
+    dish.rows()
         .enumerate()
         .map(|(i, row)| {
             let num_rounded_rocks = row.iter().filter(|&c| c == &b'O').count();
-            let load_per_rock = dish.len() - i;
+            let load_per_rock = dish.height() - i;
             load_per_rock * num_rounded_rocks
         })
         .sum::<usize>()
 }
 
-fn slide_dish_north_in_place(dish: &mut [Vec<u8>]) {
-    for x in 0..dish[0].len() {
-        let mut roll_until = 0;
-        for y in 0..dish.len() {
-            if dish[y][x] == b'O' {
-                if y > roll_until {
-                    dish[roll_until][x] = b'O';
-                    dish[y][x] = b'.';
-                }
-                roll_until += 1;
-            } else if dish[y][x] == b'#' {
-                roll_until = y + 1;
-            }
-        }
-    }
-}
-
-fn slide_dish_east_in_place(dish: &mut [Vec<u8>]) {
-    for y in 0..dish.len() {
-        let mut roll_until = dish[0].len() - 1;
-        for x in (0..dish[0].len()).rev() {
-            if dish[y][x] == b'O' {
-                if x < roll_until {
-                    dish[y][roll_until] = b'O';
-                    dish[y][x] = b'.';
-                }
-                roll_until -= 1;
-            } else if dish[y][x] == b'#' {
-                roll_until = x - 1;
-            }
-        }
+/// Tilts north, then west, south and east.
+fn spin_cycle(dish: &mut Grid<u8>) {
+    for dir in [Dir4::Up, Dir4::Left, Dir4::Down, Dir4::Right] {
+        slide_dish_in_place(dish, dir);
     }
 }
 
-fn slide_dish_south_in_place(dish: &mut [Vec<u8>]) {
-    for x in 0..dish[0].len() {
-        let mut roll_until = dish.len() - 1;
-        for y in (0..dish.len()).rev() {
-            if dish[y][x] == b'O' {
-                if y < roll_until {
-                    dish[roll_until][x] = b'O';
-                    dish[y][x] = b'.';
-                }
-                roll_until -= 1;
-            } else if dish[y][x] == b'#' {
-                roll_until = y - 1;
-            }
-        }
+/// Rolls the round rocks as far as they go in a direction.
+fn slide_dish_in_place(dish: &mut Grid<u8>, dir: Dir4) {
+    let (width, height) = (dish.width(), dish.height());
+    // Each direction gets its own copy of the loop, so the match isn't done for every cell
+    match dir {
+        Dir4::Up => slide_lanes(dish, width, height, |lane, i| (lane, i)),
+        Dir4::Down => slide_lanes(dish, width, height, |lane, i| (lane, height - 1 - i)),
+        Dir4::Left => slide_lanes(dish, height, width, |lane, i| (i, lane)),
+        Dir4::Right => slide_lanes(dish, height, width, |lane, i| (width - 1 - i, lane)),
     }
 }
 
-fn slide_dish_west_in_place(dish: &mut [Vec<u8>]) {
-    for y in 0..dish.len() {
+/// Rolls the round rocks to the start of each lane, `pos` gives the `i`th cell of a lane.
+fn slide_lanes(
+    dish: &mut Grid<u8>,
+    lanes: usize,
+    len: usize,
+    pos: impl Fn(usize, usize) -> (usize, usize),
+) {
+    for lane in 0..lanes {
         let mut roll_until = 0;
-        for x in 0..dish[0].len() {
-            if dish[y][x] == b'O' {
-                if x > roll_until {
-                    dish[y][roll_until] = b'O';
-                    dish[y][x] = b'.';
+        for i in 0..len {
+            match dish[pos(lane, i)] {
+                b'O' => {
+                    if i > roll_until {
+                        dish[pos(lane, roll_until)] = b'O';
+                        dish[pos(lane, i)] = b'.';
+                    }
+                    roll_until += 1;
                 }
-                roll_until += 1;
-            } else if dish[y][x] == b'#' {
-                roll_until = x + 1;
+                b'#' => roll_until = i + 1,
+                _ => {}
             }
         }
     }
 }
-
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
-    input
-        .lines()
-        .map(|line| line.as_bytes().to_vec())
-        .collect::<Vec<_>>()
-}
@@ -1,71 +1,80 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(input.trim().split(',').map(hash).sum::<usize>().to_string())
-}
+pub struct Day15;
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let operations = input
-        .trim()
-        .split(',')
-        .map(|operation| {
-            if let Some(label) = operation.strip_suffix('-') {
-                Ok(Operation::Remove(label.to_string()))
-            } else if let Some((label, focal_length)) = operation.split_once('=') {
-                let focal_length = focal_length.parse::<usize>()?;
-                Ok(Operation::Move(label.to_string(), focal_length))
-            } else {
-                unreachable!()
-            }
-        })
-        .collect::<anyhow::Result<Vec<Operation>>>()?;
-    log::trace!("{:?}", operations);
+impl super::Solution for Day15 {
+    type Input = Vec<String>;
 
-    let mut boxes: Vec<std::collections::LinkedList<LabelledLens>> =
-        vec![std::collections::LinkedList::new(); 256];
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.trim().split(',').map(str::to_string).collect())
+    }
 
-    for op in operations.iter() {
-        match op {
-            Operation::Remove(label) => {
-                let box_ = &mut boxes[hash(label)];
-                for (i, (lens_label, _)) in box_.iter().enumerate() {
-                    if lens_label == label {
-                        // unstable feature: linked_list_remove
-                        box_.remove(i);
-                        break;
-                    }
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(input.iter().map(|step| hash(step)).sum::<usize>().into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let operations = input
+            .iter()
+            .map(|operation| {
+                if let Some(label) = operation.strip_suffix('-') {
+                    Ok(Operation::Remove(label.to_string()))
+                } else if let Some((label, focal_length)) = operation.split_once('=') {
+                    let focal_length = focal_length.parse::<usize>()?;
+                    Ok(Operation::Move(label.to_string(), focal_length))
+                } else {
+                    unreachable!()
                 }
-            }
-            Operation::Move(label, focal_length) => {
-                let box_ = &mut boxes[hash(label)];
+            })
+            .collect::<anyhow::Result<Vec<Operation>>>()?;
+        log::trace!("{:?}", operations);
 
-                let mut replaced = false;
-                for (lens_label, lens_focal_length) in box_.iter_mut() {
-                    if lens_label == label {
-                        *lens_focal_length = *focal_length;
-                        replaced = true;
-                        break;
+        let mut boxes: Vec<std::collections::LinkedList<LabelledLens>> =
+            vec![std::collections::LinkedList::new(); 256];
+
+        for op in operations.iter() {
+            match op {
+                Operation::Remove(label) => {
+                    let box_ = &mut boxes[hash(label)];
+                    for (i, (lens_label, _)) in box_.iter().enumerate() {
+                        if lens_label == label {
+                            // unstable feature: linked_list_remove
+                            box_.remove(i);
+                            break;
+                        }
                     }
                 }
+                Operation::Move(label, focal_length) => {
+                    let box_ = &mut boxes[hash(label)];
+
+                    let mut replaced = false;
+                    for (lens_label, lens_focal_length) in box_.iter_mut() {
+                        if lens_label == label {
+                            *lens_focal_length = *focal_length;
+                            replaced = true;
+                            break;
+                        }
+                    }
 
-                if !replaced {
-                    let labelled_lens = (label.clone(), *focal_length);
-                    box_.push_back(labelled_lens);
+                    if !replaced {
+                        let labelled_lens = (label.clone(), *focal_length);
+                        box_.push_back(labelled_lens);
+                    }
                 }
             }
         }
-    }
 
-    let focusing_power = boxes
-        .iter()
-        .enumerate()
-        .map(|(box_idx, box_)| {
-            box_.iter()
-                .enumerate()
-                .map(|(lens_idx, (_, focal_length))| (box_idx + 1) * (lens_idx + 1) * focal_length)
-                .sum::<usize>()
-        })
-        .sum::<usize>();
+        let focusing_power = boxes
+            .iter()
+            .enumerate()
+            .map(|(box_idx, box_)| {
+                box_.iter()
+                    .enumerate()
+                    .map(|(lens_idx, (_, focal_length))| (box_idx + 1) * (lens_idx + 1) * focal_length)
+                    .sum::<usize>()
+            })
+            .sum::<usize>();
 
-    Ok(focusing_power.to_string())
+        Ok(focusing_power.into())
+    }
 }
 
 fn hash(step: &str) -> usize {
@@ -1,93 +1,103 @@
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(compute_energy(
-        &Beam {
-            position: (0, 0),
-            direction: 0,
-            origin: ((0, 0), 0),
-        },
-        &parse_input(input),
-    )
-    .to_string())
-}
+use crate::util::geom::Dir4;
+use crate::util::grid::{Grid, Pos};
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let contraption = parse_input(input);
-    let beams = {
-        let horizontal = (0..contraption.len()).flat_map(|y| {
-            let left = (0, y as i32);
-            let right = (contraption[0].len() as i32 - 1, y as i32);
-            [
-                Beam {
-                    position: left,
-                    direction: 0,
-                    origin: (left, 0),
-                },
-                Beam {
-                    position: right,
-                    direction: 2,
-                    origin: (right, 2),
-                },
-            ]
-        });
-        let vertical = (0..contraption[0].len()).flat_map(|x| {
-            let top = (x as i32, 0);
-            let bottom = (x as i32, contraption.len() as i32 - 1);
-            [
-                Beam {
-                    position: top,
-                    direction: 1,
-                    origin: (top, 1),
-                },
-                Beam {
-                    position: bottom,
-                    direction: 3,
-                    origin: (bottom, 3),
-                },
-            ]
-        });
-        horizontal.chain(vertical)
-    };
-
-    Ok(beams
-        .collect::<Vec<_>>()
-        .par_iter()
-        .map(|beam| compute_energy(beam, &contraption))
-        .max()
-        .ok_or_else(|| anyhow::anyhow!("Failed to find max in empty iterator"))?
-        .to_string())
+pub struct Day16;
+
+impl super::Solution for Day16 {
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(compute_energy(
+            &Beam {
+                position: (0, 0),
+                direction: Dir4::Right,
+                origin: ((0, 0), Dir4::Right),
+            },
+            input,
+        )
+        .into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let contraption = input;
+        let beams = {
+            let horizontal = (0..contraption.height()).flat_map(|y| {
+                let left = (0, y);
+                let right = (contraption.width() - 1, y);
+                [
+                    Beam {
+                        position: left,
+                        direction: Dir4::Right,
+                        origin: (left, Dir4::Right),
+                    },
+                    Beam {
+                        position: right,
+                        direction: Dir4::Left,
+                        origin: (right, Dir4::Left),
+                    },
+                ]
+            });
+            let vertical = (0..contraption.width()).flat_map(|x| {
+                let top = (x, 0);
+                let bottom = (x, contraption.height() - 1);
+                [
+                    Beam {
+                        position: top,
+                        direction: Dir4::Down,
+                        origin: (top, Dir4::Down),
+                    },
+                    Beam {
+                        position: bottom,
+                        direction: Dir4::Up,
+                        origin: (bottom, Dir4::Up),
+                    },
+                ]
+            });
+            horizontal.chain(vertical)
+        };
+
+        Ok(beams
+            .collect::<Vec<_>>()
+            .par_iter()
+            .map(|beam| compute_energy(beam, contraption))
+            .max()
+            .ok_or_else(|| anyhow::anyhow!("Failed to find max in empty iterator"))?
+            .into())
+    }
 }
 
-fn compute_energy(beam: &Beam, contraption: &[Vec<Tile>]) -> usize {
-    let mut contraption = contraption.to_vec();
-    trace_beams(&[beam.clone()], &mut contraption);
+fn compute_energy(beam: &Beam, contraption: &Grid<Tile>) -> usize {
+    let mut contraption = contraption.clone();
+    trace_beams(std::slice::from_ref(beam), &mut contraption);
 
     contraption
         .iter()
-        .flat_map(|row| row.iter())
         .filter(|tile| tile.visited.iter().any(|&b| b))
         .count()
 }
 
-fn trace_beams(beams: &[Beam], contraption: &mut [Vec<Tile>]) {
+fn trace_beams(beams: &[Beam], contraption: &mut Grid<Tile>) {
     let mut beams = beams.to_vec();
 
     while let Some(beam) = beams.pop() {
-        let (x, y) = beam.position;
-        if x < 0 || y < 0 || y >= contraption.len() as i32 || x >= contraption[0].len() as i32 {
-            continue;
-        }
-
-        let tile = &mut contraption[y as usize][x as usize];
+        let tile = &mut contraption[beam.position];
         if tile.visited[beam.direction as usize] {
             continue;
         }
         tile.visited[beam.direction as usize] = true;
 
-        for ((dx, dy), dir) in interact(tile.kind, beam.direction) {
+        for dir in interact(tile.kind, beam.direction) {
+            let Some(position) = contraption.neighbour(beam.position, dir) else {
+                continue;
+            };
             beams.push(Beam {
-                position: (x + dx, y + dy),
+                position,
                 direction: dir,
                 origin: if dir == beam.direction {
                     beam.origin
@@ -99,79 +109,39 @@ fn trace_beams(beams: &[Beam], contraption: &mut [Vec<Tile>]) {
     }
 }
 
-fn interact(tile_kind: u8, direction: u8) -> Vec<((i32, i32), u8)> {
-    let mut beams = Vec::with_capacity(2);
-    match tile_kind {
-        b'.' => match direction {
-            0 => beams.push(((1, 0), direction)),
-            1 => beams.push(((0, 1), direction)),
-            2 => beams.push(((-1, 0), direction)),
-            3 => beams.push(((0, -1), direction)),
-            _ => unreachable!(),
-        },
-        b'-' => match direction {
-            0 => beams.push(((1, 0), direction)),
-            2 => beams.push(((-1, 0), direction)),
-            1 | 3 => {
-                beams.push(((-1, 0), 2));
-                beams.push(((1, 0), 0));
-            }
-            _ => unreachable!(),
-        },
-        b'|' => match direction {
-            0 | 2 => {
-                beams.push(((0, -1), 3));
-                beams.push(((0, 1), 1));
-            }
-            1 => beams.push(((0, 1), direction)),
-            3 => beams.push(((0, -1), direction)),
-            _ => unreachable!(),
-        },
-        b'/' => match direction {
-            0 => beams.push(((0, -1), 3)),
-            1 => beams.push(((-1, 0), 2)),
-            2 => beams.push(((0, 1), 1)),
-            3 => beams.push(((1, 0), 0)),
-            _ => unreachable!(),
-        },
-        b'\\' => match direction {
-            0 => beams.push(((0, 1), 1)),
-            1 => beams.push(((1, 0), 0)),
-            2 => beams.push(((0, -1), 3)),
-            3 => beams.push(((-1, 0), 2)),
-            _ => unreachable!(),
-        },
-        _ => unreachable!(),
-    };
-    beams
+fn interact(tile_kind: u8, direction: Dir4) -> Vec<Dir4> {
+    match (tile_kind, direction) {
+        (b'-', Dir4::Down | Dir4::Up) => vec![Dir4::Left, Dir4::Right],
+        (b'|', Dir4::Right | Dir4::Left) => vec![Dir4::Up, Dir4::Down],
+        (b'/', dir) if dir.is_horizontal() => vec![dir.turn_left()],
+        (b'/', dir) => vec![dir.turn_right()],
+        (b'\\', dir) if dir.is_horizontal() => vec![dir.turn_right()],
+        (b'\\', dir) => vec![dir.turn_left()],
+        _ => vec![direction],
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<Tile>> {
-    input
-        .lines()
-        .map(|line| {
-            line.as_bytes()
-                .iter()
-                .map(|&c| Tile {
-                    kind: c,
-                    visited: [false; 4],
-                })
-                .collect()
-        })
-        .collect()
+fn parse_input(input: &str) -> anyhow::Result<Grid<Tile>> {
+    Grid::parse_with(input, |c, _| match c {
+        b'.' | b'-' | b'|' | b'/' | b'\\' => Ok(Tile {
+            kind: c,
+            visited: [false; 4],
+        }),
+        _ => anyhow::bail!("Invalid tile: '{}'", c as char),
+    })
 }
 
 #[derive(Clone)]
-struct Tile {
+pub struct Tile {
     kind: u8,
     visited: [bool; 4],
 }
 
 #[derive(Clone, Eq, PartialEq)]
 struct Beam {
-    position: (i32, i32),
-    direction: u8,
-    origin: ((i32, i32), u8),
+    position: Pos,
+    direction: Dir4,
+    origin: (Pos, Dir4),
 }
 
 impl std::hash::Hash for Beam {
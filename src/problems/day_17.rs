@@ -1,64 +1,62 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(minimize_heat_loss(parse_input(input), 1, 3).to_string())
-}
-
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok(minimize_heat_loss(parse_input(input), 4, 10).to_string())
-}
+use crate::util::geom::Dir4;
+use crate::util::graph;
+use crate::util::grid::Grid;
 
-fn minimize_heat_loss(grid: Vec<Vec<i32>>, min_dist: i32, max_dist: i32) -> i32 {
-    let mut visited = vec![vec![[false, false]; grid[0].len()]; grid.len()];
-    let mut heat_loss = vec![vec![[i32::MAX, i32::MAX]; grid[0].len()]; grid.len()];
+pub struct Day17;
 
-    let mut heap = std::collections::BinaryHeap::new();
-    heap.push((std::cmp::Reverse(0), (0, 0), 0));
-    heap.push((std::cmp::Reverse(0), (0, 0), 1));
+impl super::Solution for Day17 {
+    type Input = Grid<i32>;
 
-    let (end_x, end_y) = (grid[0].len() - 1, grid.len() - 1);
-
-    while let Some((std::cmp::Reverse(total_heat_loss), (x, y), dir)) = heap.pop() {
-        if x == end_x && y == end_y {
-            return total_heat_loss;
-        }
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
 
-        if visited[y][x][dir] {
-            continue;
-        }
-        visited[y][x][dir] = true;
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(minimize_heat_loss(input, 1, 3)?.into())
+    }
 
-        for sign in [-1_i32, 1_i32] {
-            let mut heat_loss_sum = 0;
-            for dist in 1..=max_dist {
-                let x = x as i32 + sign * ((dir as i32 + 1) % 2) * dist;
-                let y = y as i32 + sign * (dir as i32 % 2) * dist;
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(minimize_heat_loss(input, 4, 10)?.into())
+    }
+}
 
-                if y >= 0 && y < grid.len() as i32 && x >= 0 && x < grid[0].len() as i32 {
-                    heat_loss_sum += grid[y as usize][x as usize];
+/// Dijkstra over the position and whether the last move was horizontal,
+/// as the only way to continue is turning left or right.
+fn minimize_heat_loss(grid: &Grid<i32>, min_dist: isize, max_dist: isize) -> anyhow::Result<i32> {
+    let end = (grid.width() - 1, grid.height() - 1);
+
+    let path = graph::dijkstra_dense(
+        [((0, 0), false), ((0, 0), true)],
+        grid.width() * grid.height() * 2,
+        |&((x, y), horizontal)| (y * grid.width() + x) * 2 + horizontal as usize,
+        |&(pos, horizontal), moves| {
+            let turns = if horizontal {
+                [Dir4::Up, Dir4::Down]
+            } else {
+                [Dir4::Left, Dir4::Right]
+            };
+
+            for dir in turns {
+                let mut heat_loss_sum = 0;
+                for dist in 1..=max_dist {
+                    let Some(next) = grid.offset(pos, dir.offset() * dist) else {
+                        break;
+                    };
+                    heat_loss_sum += grid[next];
 
                     if dist >= min_dist {
-                        let new_total_heat_loss = total_heat_loss + heat_loss_sum;
-                        let min_heat_loss = &mut heat_loss[y as usize][x as usize][dir % 2];
-                        if new_total_heat_loss < *min_heat_loss {
-                            *min_heat_loss = new_total_heat_loss;
-                            let new_dir = (dir + 1) % 2;
-                            heap.push((
-                                std::cmp::Reverse(new_total_heat_loss),
-                                (x as usize, y as usize),
-                                new_dir,
-                            ));
-                        }
+                        moves.push(((next, dir.is_horizontal()), heat_loss_sum));
                     }
                 }
             }
-        }
-    }
+        },
+        |&(pos, _)| pos == end,
+    );
 
-    unreachable!()
+    path.map(|path| path.cost)
+        .ok_or_else(|| anyhow::anyhow!("Failed to find a path to the factory"))
 }
 
-fn parse_input(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
-        .map(|line| line.as_bytes().iter().map(|c| (c - b'0') as i32).collect())
-        .collect()
+fn parse_input(input: &str) -> anyhow::Result<Grid<i32>> {
+    Grid::parse_with(input, |c, _| Ok((c - b'0') as i32))
 }
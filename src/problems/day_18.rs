@@ -1,55 +1,61 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let instructions = parse_input(input)?;
-    Ok(calculate_area(instructions)?.to_string())
-}
+use crate::util::geom::{Dir4, Point2};
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let instructions = parse_input(input)?
-        .iter()
-        .map(|instruction| {
-            let hex = instruction.color[2..8].to_string();
+pub struct Day18;
 
-            let length = i64::from_str_radix(&hex[..hex.len() - 1], 16)?;
-            let direction = (match &hex[hex.len() - 1..] {
-                "0" => "R",
-                "1" => "D",
-                "2" => "L",
-                "3" => "U",
-                _ => anyhow::bail!("Invalid color: {}", hex),
-            })
-            .to_string();
-            Ok(DigInstruction {
-                direction,
-                length,
-                color: hex,
+impl super::Solution for Day18 {
+    type Input = Vec<DigInstruction>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(calculate_area(input).into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let instructions = input
+            .iter()
+            .map(|instruction| {
+                let hex = instruction.color[2..8].to_string();
+
+                let length = i64::from_str_radix(&hex[..hex.len() - 1], 16)?;
+                // The last digit counts clockwise from right
+                let direction = match &hex[hex.len() - 1..] {
+                    "0" => Dir4::Right,
+                    "1" => Dir4::Down,
+                    "2" => Dir4::Left,
+                    "3" => Dir4::Up,
+                    _ => anyhow::bail!("Invalid color: {}", hex),
+                };
+                Ok(DigInstruction {
+                    direction,
+                    length,
+                    color: hex,
+                })
             })
-        })
-        .collect::<anyhow::Result<Vec<_>>>()?;
-    Ok(calculate_area(instructions)?.to_string())
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(calculate_area(&instructions).into())
+    }
 }
 
-fn calculate_area(instructions: Vec<DigInstruction>) -> anyhow::Result<i64> {
-    let (mut x, mut y) = (0, 0);
+fn calculate_area(instructions: &[DigInstruction]) -> i64 {
+    let mut pos = Point2::new(0, 0);
 
     let mut inner_area = 0;
     let mut border_area = 0;
 
     for instruction in instructions {
-        let (new_x, new_y) = match instruction.direction.as_str() {
-            "R" => (x + instruction.length, y),
-            "D" => (x, y + instruction.length),
-            "L" => (x - instruction.length, y),
-            "U" => (x, y - instruction.length),
-            _ => anyhow::bail!("Invalid direction: {}", instruction.direction),
-        };
+        let new_pos = pos.step(instruction.direction, instruction.length);
 
-        inner_area += (x - new_x) * (y + new_y);
+        inner_area += (pos.x - new_pos.x) * (pos.y + new_pos.y);
         border_area += instruction.length;
 
-        (x, y) = (new_x, new_y);
+        pos = new_pos;
     }
 
-    Ok((inner_area + border_area) / 2 + 1)
+    // The sign of the shoelace sum depends on whether the trench goes clockwise
+    (inner_area.abs() + border_area) / 2 + 1
 }
 
 fn parse_input(input: &str) -> anyhow::Result<Vec<DigInstruction>> {
@@ -57,7 +63,7 @@ fn parse_input(input: &str) -> anyhow::Result<Vec<DigInstruction>> {
         .lines()
         .map(|line| match line.split(' ').collect::<Vec<&str>>()[..] {
             [direction, length, color] => Ok(DigInstruction {
-                direction: direction.to_string(),
+                direction: direction.parse()?,
                 length: length.parse()?,
                 color: color.to_string(),
             }),
@@ -66,8 +72,8 @@ fn parse_input(input: &str) -> anyhow::Result<Vec<DigInstruction>> {
         .collect::<anyhow::Result<_>>()
 }
 
-struct DigInstruction {
-    direction: String,
+pub struct DigInstruction {
+    direction: Dir4,
     length: i64,
     color: String,
 }
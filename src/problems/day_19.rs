@@ -1,41 +1,48 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let (workflows, parts) = parse_input(input)?;
-    let accepted_parts = sort_parts(&workflows, &parts)?;
-    let sum = accepted_parts
-        .iter()
-        .map(|part| part.x + part.m + part.a + part.s)
-        .sum::<u64>();
-    Ok(sum.to_string())
-}
+use crate::util::interval::{Cuboid, Interval};
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let (workflows, _) = parse_input(input)?;
+pub struct Day19;
 
-    let workflow_map = workflows
-        .iter()
-        .map(|workflow| (workflow.name.clone(), workflow))
-        .collect::<hashbrown::hash_map::HashMap<_, _>>();
+impl super::Solution for Day19 {
+    type Input = (Vec<Workflow>, Vec<Part>);
 
-    let workflow = workflow_map
-        .get("in")
-        .ok_or_else(|| anyhow::anyhow!("Failed to find workflow 'in'"))?;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let (workflows, parts) = input;
+        let accepted_parts = sort_parts(workflows, parts)?;
+        let sum = accepted_parts
+            .iter()
+            .map(|part| part.ratings.iter().sum::<u64>())
+            .sum::<u64>();
+        Ok(sum.into())
+    }
+
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let (workflows, _) = input;
+
+        let workflow_map = workflows
+            .iter()
+            .map(|workflow| (workflow.name.clone(), workflow))
+            .collect::<hashbrown::hash_map::HashMap<_, _>>();
 
-    let ranges = RangeSet {
-        x: 1..4001,
-        m: 1..4001,
-        a: 1..4001,
-        s: 1..4001,
-    };
+        let workflow = workflow_map
+            .get("in")
+            .ok_or_else(|| anyhow::anyhow!("Failed to find workflow 'in'"))?;
 
-    let combinations = count_combinations(&workflow_map, workflow, ranges)?;
+        let ranges = Cuboid([Interval::new(1, 4001); 4]);
 
-    Ok(combinations.to_string())
+        let combinations = count_combinations(&workflow_map, workflow, ranges)?;
+
+        Ok(combinations.into())
+    }
 }
 
 fn count_combinations(
     workflow_map: &hashbrown::HashMap<String, &Workflow>,
     workflow: &Workflow,
-    mut ranges: RangeSet,
+    mut ranges: Cuboid<u64, 4>,
 ) -> anyhow::Result<u64> {
     let mut count = 0;
 
@@ -53,7 +60,7 @@ fn count_combinations(
             Action::Accept => {
                 // Count combinations inside the range,
                 // continue with the rest
-                count += included.size();
+                count += included.volume();
                 ranges = rest;
             }
             Action::Send(target_workflow) => {
@@ -71,14 +78,17 @@ fn count_combinations(
     Ok(count)
 }
 
-fn split_range_set(ranges: &RangeSet, condition: &Option<Condition>) -> (RangeSet, RangeSet) {
+/// Splits the ranges into the part matching the condition and the rest.
+fn split_range_set(
+    ranges: &Cuboid<u64, 4>,
+    condition: &Option<Condition>,
+) -> (Cuboid<u64, 4>, Cuboid<u64, 4>) {
     match condition {
-        None => (ranges.clone(), RangeSet::EMPTY),
+        None => (*ranges, Cuboid([Interval::new(0, 0); 4])),
         Some(condition) => match condition.operator {
-            b'<' => split_range_set_before(ranges, condition.value, condition.category),
+            b'<' => ranges.split(condition.category, condition.value),
             b'>' => {
-                let (rest, included) =
-                    split_range_set_before(ranges, condition.value + 1, condition.category);
+                let (rest, included) = ranges.split(condition.category, condition.value + 1);
                 (included, rest)
             }
             _ => unreachable!(),
@@ -86,56 +96,6 @@ fn split_range_set(ranges: &RangeSet, condition: &Option<Condition>) -> (RangeSe
     }
 }
 
-fn split_range_set_before(ranges: &RangeSet, before: u64, category: u8) -> (RangeSet, RangeSet) {
-    let front = RangeSet {
-        x: if category == b'x' {
-            ranges.x.start..before
-        } else {
-            ranges.x.clone()
-        },
-        m: if category == b'm' {
-            ranges.m.start..before
-        } else {
-            ranges.m.clone()
-        },
-        a: if category == b'a' {
-            ranges.a.start..before
-        } else {
-            ranges.a.clone()
-        },
-        s: if category == b's' {
-            ranges.s.start..before
-        } else {
-            ranges.s.clone()
-        },
-    };
-
-    let back = RangeSet {
-        x: if category == b'x' {
-            before..ranges.x.end
-        } else {
-            ranges.x.clone()
-        },
-        m: if category == b'm' {
-            before..ranges.m.end
-        } else {
-            ranges.m.clone()
-        },
-        a: if category == b'a' {
-            before..ranges.a.end
-        } else {
-            ranges.a.clone()
-        },
-        s: if category == b's' {
-            before..ranges.s.end
-        } else {
-            ranges.s.clone()
-        },
-    };
-
-    (front, back)
-}
-
 fn sort_parts(workflows: &[Workflow], parts: &[Part]) -> anyhow::Result<Vec<Part>> {
     let mut accepted = Vec::with_capacity(parts.len());
 
@@ -185,13 +145,7 @@ fn apply_workflows(
 fn apply_workflow(part: &Part, workflow: &Workflow) -> Action {
     for rule in workflow.rules.iter() {
         if let Some(condition) = &rule.condition {
-            let operand = match condition.category {
-                b'x' => part.x,
-                b'm' => part.m,
-                b'a' => part.a,
-                b's' => part.s,
-                _ => unreachable!(),
-            };
+            let operand = part.ratings[condition.category];
 
             let result = match condition.operator {
                 b'<' => operand < condition.value,
@@ -233,12 +187,7 @@ fn parse_part(line: &str) -> anyhow::Result<Part> {
         .strip_suffix('}')
         .ok_or_else(|| anyhow::anyhow!("Failed to strip part suffix }}"))?;
 
-    let mut part = Part {
-        x: 0,
-        m: 0,
-        a: 0,
-        s: 0,
-    };
+    let mut part = Part { ratings: [0; 4] };
 
     for attr in attributes.split(',') {
         let (key, value) = attr
@@ -247,13 +196,7 @@ fn parse_part(line: &str) -> anyhow::Result<Part> {
 
         let value = value.parse::<u64>()?;
 
-        match key {
-            "x" => part.x = value,
-            "m" => part.m = value,
-            "a" => part.a = value,
-            "s" => part.s = value,
-            _ => anyhow::bail!("Invalid attribute key: {}", key),
-        }
+        part.ratings[parse_category(key)?] = value;
     }
 
     Ok(part)
@@ -274,7 +217,7 @@ fn parse_workflow(line: &str) -> anyhow::Result<Workflow> {
             let (condition, workflow_action) =
                 if let Some((condition, target_workflow)) = rule.split_once(':') {
                     let chars = condition.as_bytes();
-                    let category = chars[0];
+                    let category = parse_category(&condition[..1])?;
                     let operator = chars[1];
 
                     let value = condition[2..].parse::<u64>().map_err(|e| {
@@ -301,6 +244,17 @@ fn parse_workflow(line: &str) -> anyhow::Result<Workflow> {
     Ok(Workflow { name, rules })
 }
 
+/// The index of a category in the ratings of a part.
+fn parse_category(category: &str) -> anyhow::Result<usize> {
+    match category {
+        "x" => Ok(0),
+        "m" => Ok(1),
+        "a" => Ok(2),
+        "s" => Ok(3),
+        _ => anyhow::bail!("Invalid category: {}", category),
+    }
+}
+
 fn parse_action(action: &str) -> Action {
     match action {
         "A" => Action::Accept,
@@ -309,7 +263,7 @@ fn parse_action(action: &str) -> Action {
     }
 }
 
-struct Workflow {
+pub struct Workflow {
     name: String,
     rules: Vec<WorkflowRule>,
 }
@@ -321,7 +275,7 @@ struct WorkflowRule {
 
 #[derive(Clone)]
 struct Condition {
-    category: u8,
+    category: usize,
     operator: u8,
     value: u64,
 }
@@ -333,34 +287,8 @@ enum Action {
     Accept,
 }
 
+/// The ratings of a part, in `x`, `m`, `a`, `s` order.
 #[derive(Debug, Clone)]
-struct Part {
-    x: u64,
-    m: u64,
-    a: u64,
-    s: u64,
-}
-
-#[derive(Clone)]
-struct RangeSet {
-    x: std::ops::Range<u64>,
-    m: std::ops::Range<u64>,
-    a: std::ops::Range<u64>,
-    s: std::ops::Range<u64>,
-}
-
-impl RangeSet {
-    const EMPTY: Self = Self {
-        x: 0..0,
-        m: 0..0,
-        a: 0..0,
-        s: 0..0,
-    };
-
-    fn size(&self) -> u64 {
-        (self.x.end - self.x.start).max(0)
-            * (self.m.end - self.m.start).max(0)
-            * (self.a.end - self.a.start).max(0)
-            * (self.s.end - self.s.start).max(0)
-    }
+pub struct Part {
+    ratings: [u64; 4],
 }
@@ -1,20 +1,30 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let mut machines = parse_input(input)?;
+pub struct Day20;
 
-    let (mut low_sent, mut high_sent) = (0, 0);
-    for _ in 1..=1000 {
-        let (low, high) = push_button(&mut machines)?;
+impl super::Solution for Day20 {
+    type Input = hashbrown::hash_map::HashMap<String, Machine>;
 
-        low_sent += low;
-        high_sent += high;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
     }
 
-    let prod = low_sent * high_sent;
-    Ok(prod.to_string())
-}
+    fn part_one(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        let mut machines = input.clone();
+
+        let (mut low_sent, mut high_sent) = (0, 0);
+        for _ in 1..=params.get("presses", 1000)? {
+            let (low, high) = push_button(&mut machines)?;
+
+            low_sent += low;
+            high_sent += high;
+        }
 
-pub fn part_two(_input: &str) -> anyhow::Result<String> {
-    Ok("not implemented".to_string())
+        let prod = low_sent * high_sent;
+        Ok(prod.into())
+    }
+
+    fn part_two(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(super::Answer::NotImplemented)
+    }
 }
 
 fn push_button(
@@ -141,7 +151,7 @@ fn parse_input(input: &str) -> anyhow::Result<hashbrown::hash_map::HashMap<Strin
 }
 
 #[derive(Clone, Debug, PartialEq)]
-enum Pulse {
+pub enum Pulse {
     Low,
     High,
 }
@@ -168,8 +178,8 @@ impl std::fmt::Display for Signal {
     }
 }
 
-#[derive(Debug)]
-enum Machine {
+#[derive(Clone, Debug)]
+pub enum Machine {
     Broadcaster {
         destinations: Vec<String>,
     },
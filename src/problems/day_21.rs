@@ -1,95 +1,47 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let (mut grid, start) = parse_input(input)?;
+use crate::util::graph;
+use crate::util::grid::{Grid, Pos};
 
-    let mut queue = std::collections::VecDeque::from([QueueNode {
-        pos: start,
-        steps: 0,
-    }]);
+pub struct Day21;
 
-    let max_steps = 64;
-    let neighbor_pos = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+impl super::Solution for Day21 {
+    type Input = ParsedGrid;
 
-    while let Some(curr) = queue.pop_front() {
-        let (x, y) = curr.pos;
-
-        let n = &mut grid[y as usize][x as usize];
-        if n.visited > 0 {
-            continue;
-        }
-        n.visited = curr.steps;
-
-        if curr.steps >= max_steps {
-            continue;
-        }
-
-        for (dx, dy) in neighbor_pos {
-            let (n_x, n_y) = (x + dx, y + dy);
-            if n_y < 0
-                || n_y >= grid.len() as i32
-                || n_x < 0
-                || n_x >= grid[y as usize].len() as i32
-            {
-                continue;
-            }
-
-            let n = &grid[n_y as usize][n_x as usize];
-            if n.visited == 0 && n.kind == b'.' {
-                queue.push_back(QueueNode {
-                    pos: (n_x, n_y),
-                    steps: curr.steps + 1,
-                });
-            }
-        }
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
     }
 
-    let n_visited = grid
-        .iter()
-        .flatten()
-        .filter(|n| n.visited > 0 && n.visited % 2 == 0)
-        .count();
-
-    Ok(n_visited.to_string())
-}
+    fn part_one(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        let (grid, start) = input;
+        let max_steps = params.get("steps", 64)?;
 
-pub fn part_two(_input: &str) -> anyhow::Result<String> {
-    Ok("not implemented".to_string())
-}
+        // A plot can be reached in exactly `max_steps` when its distance has the same parity,
+        // the remaining steps are spent walking back and forth
+        let n_visited = graph::bfs(*start, |&pos| {
+            grid.neighbours4(pos).filter(|&n| grid[n] == b'.')
+        })
+        .take_while(|&(_, steps)| steps <= max_steps)
+        .filter(|&(_, steps)| steps % 2 == max_steps % 2)
+        .count();
 
-struct QueueNode {
-    pos: (i32, i32),
-    steps: usize,
-}
+        Ok(n_visited.into())
+    }
 
-struct GridCell {
-    kind: u8,
-    visited: usize,
+    fn part_two(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(super::Answer::NotImplemented)
+    }
 }
 
-type ParsedGrid = (Vec<Vec<GridCell>>, (i32, i32));
+type ParsedGrid = (Grid<u8>, Pos);
 
 fn parse_input(input: &str) -> anyhow::Result<ParsedGrid> {
     let mut start = None;
 
-    let grid = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .map(|(x, &b)| {
-                    if b == b'S' {
-                        start = Some((x as i32, y as i32));
-                    }
-                    let b = if b == b'#' { b'#' } else { b'.' };
-                    GridCell {
-                        kind: b,
-                        visited: 0,
-                    }
-                })
-                .collect()
-        })
-        .collect();
+    let grid = Grid::parse_with(input, |b, pos| {
+        if b == b'S' {
+            start = Some(pos);
+        }
+        Ok(if b == b'#' { b'#' } else { b'.' })
+    })?;
 
     Ok((
         grid,
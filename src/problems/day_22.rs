@@ -1,81 +1,102 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let tower = compress_tower(parse_input(input)?);
-    let result = tower
-        .nodes
-        .iter()
-        .filter(|node| {
-            node.outputs.iter().all(|&out_idx| {
-                let out_node = &tower.nodes[out_idx];
-                out_node.inputs.len() > 1
-            })
-        })
-        .count();
+use crate::util::graph::Graph;
+use crate::util::interval::{Cuboid, Interval};
 
-    Ok(result.to_string())
-}
+pub struct Day22;
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let tower = compress_tower(parse_input(input)?);
-    let result = tower
-        .nodes
-        .iter()
-        .map(|node| {
-            let mut queue = std::collections::VecDeque::from([node]);
-            let mut removed = hashbrown::HashSet::new();
-
-            while let Some(node) = queue.pop_front() {
-                if !removed.insert(node.idx) {
-                    continue;
-                }
+impl super::Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let tower = compress_tower(input.clone());
+        let result = tower
+            .ids()
+            .filter(|&idx| {
+                tower
+                    .neighbours(idx)
+                    .iter()
+                    .all(|upper| tower.predecessors(upper.to).len() > 1)
+            })
+            .count();
+
+        Ok(result.into())
+    }
 
-                for &out_idx in &node.outputs {
-                    let out_node = &tower.nodes[out_idx];
-                    if out_node
-                        .inputs
-                        .iter()
-                        .all(|&in_idx| removed.contains(&in_idx))
+    fn part_two(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        let tower = compress_tower(input.clone());
+        let order = tower.topological_sort()?;
+
+        let result = tower
+            .ids()
+            .map(|removed_idx| {
+                // Bricks resting only on fallen bricks fall too, the ground never does
+                let mut fallen = vec![false; tower.len()];
+                fallen[removed_idx] = true;
+                let mut count = 0;
+
+                for &idx in order.iter() {
+                    let supports = tower.predecessors(idx);
+                    if idx != removed_idx
+                        && !supports.is_empty()
+                        && supports.iter().all(|support| fallen[support.to])
                     {
-                        queue.push_back(out_node);
+                        fallen[idx] = true;
+                        count += 1;
                     }
                 }
-            }
 
-            removed.len() - 1
-        })
-        .sum::<usize>();
-    Ok(result.to_string())
+                count
+            })
+            .sum::<usize>();
+        Ok(result.into())
+    }
 }
 
-fn compress_tower(mut bricks: Vec<Brick>) -> CompressedTower {
-    bricks.sort_by_key(|brick| *brick.z.start());
+/// Lets the bricks fall, returning which brick rests on which.
+///
+/// The node ids are the brick indices, edges point from a brick to the bricks resting on it.
+fn compress_tower(mut bricks: Vec<Brick>) -> Graph<usize> {
+    let mut tower = Graph::new();
+    for idx in 0..bricks.len() {
+        tower.intern(idx);
+    }
+
+    bricks.sort_by_key(|brick| brick.cuboid.axes()[2].start);
 
     let (dim_x, dim_y) = bricks.iter().fold((0, 0), |(x, y), brick| {
-        (x.max(*brick.x.end() + 1), y.max(*brick.y.end() + 1))
+        let [brick_x, brick_y, _] = brick.cuboid.axes();
+        (x.max(brick_x.end), y.max(brick_y.end))
     });
     let mut grid: Vec<Vec<(usize, Option<usize>)>> = vec![vec![(0, None); dim_y]; dim_x];
     let mut edges = hashbrown::HashSet::new();
 
-    for brick in bricks.iter_mut() {
+    for brick in bricks.iter() {
+        let [brick_x, brick_y, brick_z] = *brick.cuboid.axes();
         let mut max_z = 0;
-        let brick_height = brick.z.end() - brick.z.start() + 1;
+        let brick_height = brick_z.len();
 
         // Find the current max z
-        for x in brick.x.clone() {
-            for y in brick.y.clone() {
+        for x in brick_x.range() {
+            for y in brick_y.range() {
                 let (z, _) = grid[x][y];
                 max_z = max_z.max(z);
             }
         }
 
         // Update z, and store top brick ID
-        for x in brick.x.clone() {
-            for y in brick.y.clone() {
+        for x in brick_x.range() {
+            for y in brick_y.range() {
                 let (z, upper_brick_idx) = grid[x][y];
 
                 if z == max_z {
                     // Current brick is directly supported by upper_brick
                     if let Some(upper_brick_idx) = upper_brick_idx {
-                        edges.insert((upper_brick_idx, brick.idx));
+                        if edges.insert((upper_brick_idx, brick.idx)) {
+                            tower.add_edge(upper_brick_idx, brick.idx, 1);
+                        }
                     }
                 }
 
@@ -84,49 +105,7 @@ fn compress_tower(mut bricks: Vec<Brick>) -> CompressedTower {
         }
     }
 
-    let nodes = bricks
-        .iter()
-        .enumerate()
-        .map(|(brick_idx, _)| {
-            let inputs = edges
-                .iter()
-                .filter_map(|&(from_idx, to_idx)| {
-                    if to_idx == brick_idx {
-                        Some(from_idx)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            let outputs = edges
-                .iter()
-                .filter_map(|&(from_idx, to_idx)| {
-                    if from_idx == brick_idx {
-                        Some(to_idx)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            TowerNode {
-                idx: brick_idx,
-                inputs,
-                outputs,
-            }
-        })
-        .collect();
-
-    CompressedTower { nodes }
-}
-
-struct CompressedTower {
-    nodes: Vec<TowerNode>,
-}
-
-struct TowerNode {
-    idx: usize,
-    inputs: Vec<usize>,
-    outputs: Vec<usize>,
+    tower
 }
 
 fn parse_input(input: &str) -> anyhow::Result<Vec<Brick>> {
@@ -143,20 +122,20 @@ fn parse_input(input: &str) -> anyhow::Result<Vec<Brick>> {
 
             Ok(Brick {
                 idx,
-                x: from_x..=to_x,
-                y: from_y..=to_y,
-                z: from_z..=to_z,
+                cuboid: Cuboid([
+                    Interval::inclusive(from_x, to_x),
+                    Interval::inclusive(from_y, to_y),
+                    Interval::inclusive(from_z, to_z),
+                ]),
             })
         })
         .collect::<anyhow::Result<_>>()
 }
 
 #[derive(Clone)]
-struct Brick {
+pub struct Brick {
     idx: usize,
-    x: std::ops::RangeInclusive<usize>,
-    y: std::ops::RangeInclusive<usize>,
-    z: std::ops::RangeInclusive<usize>,
+    cuboid: Cuboid<usize, 3>,
 }
 
 fn parse_coordinates(s: &str) -> anyhow::Result<(usize, usize, usize)> {
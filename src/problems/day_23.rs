@@ -1,24 +1,42 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    Ok(solve(input, false)?.to_string())
-}
+use crate::util::geom::Dir4;
+use crate::util::graph::Graph;
+use crate::util::grid::{Grid, Pos};
+
+pub struct Day23;
+
+impl super::Solution for Day23 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Grid::parse(input)
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    Ok("skipped".to_string())
-    // Ok(solve(input, true)?.to_string())
+    fn part_one(input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(solve(input, false)?.into())
+    }
+
+    fn part_two(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(super::Answer::Skipped)
+        // Ok(solve(input, true)?.into())
+    }
 }
 
-fn solve(input: &str, ignore_slope: bool) -> anyhow::Result<usize> {
-    let map = parse_input(input)?;
+fn solve(map: &Grid<u8>, ignore_slope: bool) -> anyhow::Result<usize> {
+    let g = build_graph(map, ignore_slope);
+    let (start, end) = ((1, 0), (map.width() - 2, map.height() - 1));
+    let (start_idx, end_idx) = g
+        .id(&start)
+        .zip(g.id(&end))
+        .ok_or_else(|| anyhow::anyhow!("Failed to find the start and end of the trail"))?;
 
-    let g = build_graph(&map, ignore_slope);
-    let mut visited = vec![false; g.nodes.len()];
-    let res = find_longest_path_tree(&g, 1, g.nodes.len() - 2, 0, 0, &mut visited);
+    let mut visited = vec![false; g.len()];
+    let res = find_longest_path_tree(&g, start_idx, end_idx, 0, 0, &mut visited);
 
     Ok(res)
 }
 
 fn find_longest_path_tree(
-    g: &Graph,
+    g: &Graph<Pos>,
     node_idx: usize,
     end_idx: usize,
     path_len: usize,
@@ -32,17 +50,16 @@ fn find_longest_path_tree(
     visited[node_idx] = true;
     let mut new_max_path_len = max_path_len;
 
-    let node = &g.nodes[node_idx];
-    for &neighbor_idx in node.neighbors.iter() {
-        if visited[neighbor_idx] {
+    for edge in g.neighbours(node_idx) {
+        if visited[edge.to] {
             continue;
         }
 
         new_max_path_len = new_max_path_len.max(find_longest_path_tree(
             g,
-            neighbor_idx,
+            edge.to,
             end_idx,
-            path_len + 1,
+            path_len + edge.weight as usize,
             new_max_path_len,
             visited,
         ));
@@ -52,73 +69,27 @@ fn find_longest_path_tree(
     new_max_path_len
 }
 
-fn build_graph(map: &[Vec<u8>], ignore_slope: bool) -> Graph {
-    let mut nodes = map
-        .iter()
-        .flat_map(|row| {
-            row.iter()
-                .map(|_| Node { neighbors: vec![] })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    for y in 0..map.len() {
-        for x in 0..map[y].len() {
-            let cell = map[y][x];
-            if cell == b'#' {
-                continue;
-            }
+fn build_graph(map: &Grid<u8>, ignore_slope: bool) -> Graph<Pos> {
+    let mut g = Graph::new();
 
-            let moves = if ignore_slope {
-                vec![(1, 0), (0, 1), (-1, 0), (0, -1)]
-            } else {
-                match cell {
-                    b'.' => vec![(1, 0), (0, 1), (-1, 0), (0, -1)],
-                    b'>' => vec![(1, 0)],
-                    b'v' => vec![(0, 1)],
-                    b'<' => vec![(-1, 0)],
-                    b'^' => vec![(0, -1)],
-                    _ => unreachable!(),
-                }
-            };
-
-            let curr_idx = y * map[0].len() + x;
-
-            nodes[curr_idx].neighbors = moves
-                .iter()
-                .filter_map(|(dx, dy)| {
-                    let (n_x, n_y) = (x as i32 + dx, y as i32 + dy);
-                    if n_y < 0 || n_y >= map.len() as i32 || n_x < 0 || n_x >= map[0].len() as i32 {
-                        return None;
-                    }
-
-                    let n_cell = map[n_y as usize][n_x as usize];
-                    if n_cell == b'#' {
-                        return None;
-                    }
-
-                    let n_idx = n_y as usize * map[0].len() + n_x as usize;
-                    Some(n_idx)
-                })
-                .collect::<Vec<_>>();
+    for (pos, &cell) in map.enumerate() {
+        if cell == b'#' {
+            continue;
         }
-    }
+        g.intern(pos);
 
-    Graph { nodes }
-}
-
-struct Graph {
-    nodes: Vec<Node>,
-}
+        // Slopes can only be walked down
+        let moves = match Dir4::from_arrow(cell) {
+            Some(dir) if !ignore_slope => vec![dir],
+            _ => Dir4::ALL.to_vec(),
+        };
 
-#[derive(Clone, Debug)]
-struct Node {
-    neighbors: Vec<usize>,
-}
+        for dir in moves {
+            if let Some(n_pos) = map.neighbour(pos, dir).filter(|&n_pos| map[n_pos] != b'#') {
+                g.add_edge(pos, n_pos, 1);
+            }
+        }
+    }
 
-fn parse_input(input: &str) -> anyhow::Result<Vec<Vec<u8>>> {
-    Ok(input
-        .lines()
-        .map(|line| line.as_bytes().to_owned())
-        .collect())
+    g
 }
@@ -1,28 +1,40 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let hailstones = parse_input(input)?;
-    let (min, max) = (200000000000000.0, 400000000000000.0);
-    let result = hailstones
-        .iter()
-        .enumerate()
-        .flat_map(|(i, a)| {
-            hailstones[i + 1..]
-                .iter()
-                .filter(|b| match intersect(a, b) {
-                    Some((x, y)) => x >= min && x <= max && y >= min && y <= max,
-                    None => false,
-                })
-        })
-        .count();
+pub struct Day24;
 
-    Ok(result.to_string())
-}
+impl super::Solution for Day24 {
+    type Input = Vec<Hailstone>;
 
-pub fn part_two(_input: &str) -> anyhow::Result<String> {
-    // Solved with Wolfram Language:
-    // https://www.wolframcloud.com/obj/eb034536-8d34-4c7b-8ae0-1ca3cc93da51
-    // Yeah this is cheating, I'm feeling bad but it's christmas holidays
-    let result = 885093461440405_u64;
-    Ok(result.to_string())
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input, params: &super::Params) -> anyhow::Result<super::Answer> {
+        let hailstones = input;
+        let (min, max) = (
+            params.get("min", 200000000000000.0)?,
+            params.get("max", 400000000000000.0)?,
+        );
+        let result = hailstones
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                hailstones[i + 1..]
+                    .iter()
+                    .filter(|b| match intersect(a, b) {
+                        Some((x, y)) => x >= min && x <= max && y >= min && y <= max,
+                        None => false,
+                    })
+            })
+            .count();
+
+        Ok(result.into())
+    }
+
+    fn part_two(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        // Solved with Wolfram Language:
+        // https://www.wolframcloud.com/obj/eb034536-8d34-4c7b-8ae0-1ca3cc93da51
+        // Yeah this is cheating, I'm feeling bad but it's christmas holidays
+        Ok(super::Answer::hard_coded(885093461440405_u64))
+    }
 }
 
 fn intersect(a: &Hailstone, b: &Hailstone) -> Option<(f64, f64)> {
@@ -100,7 +112,7 @@ fn parse_input(input: &str) -> anyhow::Result<Vec<Hailstone>> {
 }
 
 #[derive(Debug)]
-struct Hailstone {
+pub struct Hailstone {
     pos: (f64, f64, f64),
     dir: (f64, f64, f64),
 }
@@ -1,14 +1,26 @@
-pub fn part_one(input: &str) -> anyhow::Result<String> {
-    let mut g = parse_input(input)?;
-    // collapse(&mut g)?;
-    Ok("not implemented".to_string())
-}
+use crate::util::graph;
+
+pub struct Day25;
+
+impl super::Solution for Day25 {
+    type Input = Graph;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        // collapse(&mut input.clone())?;
+        Ok(super::Answer::NotImplemented)
+    }
 
-pub fn part_two(input: &str) -> anyhow::Result<String> {
-    let _g = parse_input(input)?;
-    Ok("not implemented".to_string())
+    fn part_two(_input: &Self::Input, _params: &super::Params) -> anyhow::Result<super::Answer> {
+        Ok(super::Answer::NotImplemented)
+    }
 }
 
+// Work in progress, not wired up to part one yet
+#[allow(dead_code)]
 fn collapse(g: &mut Graph) -> anyhow::Result<()> {
     while g.nodes.len() > 2 {
         // Find 2 neighboring nodes that have
@@ -143,36 +155,29 @@ fn find_path(
     end_id: &str,
     exclude: &hashbrown::HashSet<Edge>,
 ) -> Option<Vec<Edge>> {
-    let mut heap = std::collections::BinaryHeap::from([(std::cmp::Reverse(0), start_id, vec![])]);
-    let mut visited: hashbrown::HashSet<&str> = hashbrown::HashSet::from([start_id]);
-
-    while let Some((std::cmp::Reverse(dist), node_id, path)) = heap.pop() {
-        if node_id == end_id {
-            return Some(path);
-        }
-
-        visited.insert(node_id);
-
-        let node = match g.nodes.get(node_id) {
-            Some(node) => node,
-            None => continue,
-        };
-        let edges = node.edges.iter().filter(|&edge| {
-            let neighbor_id = edge.other(node_id);
-            !visited.contains(neighbor_id) && !exclude.contains(edge)
-        });
-
-        for edge in edges {
-            let new_dist = dist + 1;
-            let new_node_id = edge.other(node_id);
-            let mut new_path = path.clone();
-            new_path.push(edge.clone());
-
-            heap.push((std::cmp::Reverse(new_dist), new_node_id, new_path));
-        }
-    }
-
-    None
+    let path = graph::dijkstra(
+        [start_id],
+        |&node_id| {
+            g.nodes
+                .get(node_id)
+                .into_iter()
+                .flat_map(|node| node.edges.iter())
+                .filter(|&edge| !exclude.contains(edge))
+                .map(|edge| (edge.other(node_id), 1))
+                .collect::<Vec<_>>()
+        },
+        |&node_id| node_id == end_id,
+    )?;
+
+    Some(
+        path.states
+            .windows(2)
+            .map(|pair| Edge {
+                a: pair[0].to_owned(),
+                b: pair[1].to_owned(),
+            })
+            .collect(),
+    )
 }
 
 fn parse_input(input: &str) -> anyhow::Result<Graph> {
@@ -206,7 +211,7 @@ fn parse_input(input: &str) -> anyhow::Result<Graph> {
     Ok(Graph { nodes })
 }
 
-struct Graph {
+pub struct Graph {
     nodes: hashbrown::HashMap<String, Node>,
 }
 
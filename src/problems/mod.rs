@@ -1,26 +1,106 @@
-pub mod day_00;
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_19;
-pub mod day_20;
-pub mod day_21;
-pub mod day_22;
-pub mod day_23;
-pub mod day_24;
-pub mod day_25;
+mod answer;
+mod params;
+
+pub use answer::Answer;
+pub use params::{parse_param, Params};
+
+/// A single day's puzzle.
+///
+/// The input is parsed once and then shared between both parts.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part_one(input: &Self::Input, params: &Params) -> anyhow::Result<Answer>;
+
+    fn part_two(input: &Self::Input, params: &Params) -> anyhow::Result<Answer>;
+}
+
+/// Object-safe view of a [`Solution`], so days can be stored in the registry.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Parsed>>;
+}
+
+/// A day's parsed input, ready to run either part against.
+pub trait Parsed: Send + Sync {
+    fn part_one(&self, params: &Params) -> anyhow::Result<Answer>;
+
+    fn part_two(&self, params: &Params) -> anyhow::Result<Answer>;
+}
+
+impl<S: Solution + Sync + 'static> Solver for S
+where
+    S::Input: Send + Sync,
+{
+    fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Parsed>> {
+        Ok(Box::new(ParsedInput::<S> {
+            input: S::parse(input)?,
+            solution: std::marker::PhantomData,
+        }))
+    }
+}
+
+struct ParsedInput<S: Solution> {
+    input: S::Input,
+    solution: std::marker::PhantomData<fn() -> S>,
+}
+
+impl<S: Solution> Parsed for ParsedInput<S>
+where
+    S::Input: Send + Sync,
+{
+    fn part_one(&self, params: &Params) -> anyhow::Result<Answer> {
+        S::part_one(&self.input, params)
+    }
+
+    fn part_two(&self, params: &Params) -> anyhow::Result<Answer> {
+        S::part_two(&self.input, params)
+    }
+}
+
+/// Declares the day modules and maps each day to its solution.
+///
+/// Adding a day only takes a new line here, next to the module itself.
+macro_rules! solutions {
+    ($($day:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Returns the solution registered for the given day.
+        pub fn solution(day: i32) -> Option<&'static dyn Solver> {
+            match day {
+                $($day => Some(&$module::$solution),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+solutions! {
+    0 => day_00::Day00,
+    1 => day_01::Day01,
+    2 => day_02::Day02,
+    3 => day_03::Day03,
+    4 => day_04::Day04,
+    5 => day_05::Day05,
+    6 => day_06::Day06,
+    7 => day_07::Day07,
+    8 => day_08::Day08,
+    9 => day_09::Day09,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    13 => day_13::Day13,
+    14 => day_14::Day14,
+    15 => day_15::Day15,
+    16 => day_16::Day16,
+    17 => day_17::Day17,
+    18 => day_18::Day18,
+    19 => day_19::Day19,
+    20 => day_20::Day20,
+    21 => day_21::Day21,
+    22 => day_22::Day22,
+    23 => day_23::Day23,
+    24 => day_24::Day24,
+    25 => day_25::Day25,
+}
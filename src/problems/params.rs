@@ -0,0 +1,55 @@
+/// Puzzle constants that differ between the puzzle input and the examples.
+///
+/// Solutions look them up with their own default, which can be overridden by a
+/// `.params` file next to the input or with `--param key=value`.
+#[derive(Clone, Debug, Default)]
+pub struct Params(hashbrown::HashMap<String, String>);
+
+impl Params {
+    pub fn get<T>(&self, key: &str, default: T) -> anyhow::Result<T>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid parameter {}='{}': {}", key, value, e)),
+            None => Ok(default),
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    /// Reads `key=value` lines, ignoring empty lines and lines starting with `#`.
+    /// A missing file has no parameters.
+    pub fn read(path: &std::path::Path) -> anyhow::Result<Self> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut params = Self::default();
+        for line in contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        {
+            let (key, value) = parse_param(line).map_err(|e| {
+                anyhow::anyhow!("Invalid parameter file '{}': {}", path.display(), e)
+            })?;
+            params.set(&key, &value);
+        }
+        Ok(params)
+    }
+}
+
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Expected key=value, got '{}'", s))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
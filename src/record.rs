@@ -0,0 +1,65 @@
+use aoc2023::answers::{Recorded, Value};
+use aoc2023::{files, inputs};
+
+/// Records a confirmed answer for one of a day's inputs, bound to the input's fingerprint.
+///
+/// Without an answer, the solution is run and its answer is recorded,
+/// with the `--param` overrides applied like in a normal run.
+pub fn record(
+    files: &files::Files,
+    day: i32,
+    part: u8,
+    name: &str,
+    answer: Option<String>,
+    plain: bool,
+    overrides: &[(String, String)],
+) -> anyhow::Result<()> {
+    let input = inputs::find(files, day, name)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let mut params = input.params.clone();
+            for (key, value) in overrides {
+                params.set(key, value);
+            }
+            let answer = aoc2023::solve_with_params(day, part, &input.text, &params)?;
+            answer
+                .value()
+                .ok_or_else(|| anyhow::anyhow!("Part {} has no answer: {}", part, answer))?
+        }
+    };
+
+    let recorded = Recorded {
+        value: if plain {
+            Value::Plain(answer)
+        } else {
+            Value::hashed(&answer)
+        },
+        input: Some(input.fingerprint()),
+    };
+
+    let path = if name == inputs::PUZZLE_INPUT {
+        files.answers_path(day)
+    } else {
+        files.named_answers_path(day, name)
+    };
+
+    // Only say what kind of answer was replaced, the value could be a spoiler
+    if let Some(previous) = aoc2023::answers::write(&path, part, &recorded)? {
+        let kind = match previous.value {
+            Value::Plain(_) => "plain",
+            Value::Hashed { .. } => "hashed",
+        };
+        log::warn!("Replaced previous {} answer", kind);
+    }
+    log::info!(
+        "Recorded day {:02}/part {} for '{}' in '{}'",
+        day,
+        part,
+        name,
+        path.display()
+    );
+
+    Ok(())
+}
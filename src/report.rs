@@ -0,0 +1,266 @@
+use aoc2023::answers::Value;
+use aoc2023::Answer;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    Pass,
+    Fail,
+    Error,
+    /// Did not finish within its `--timeout`.
+    Timeout,
+    /// Validating, but no answer has been recorded for this part yet.
+    Unsolved,
+    /// The recorded answer belongs to a different input, so it can't be checked.
+    OtherInput,
+    /// Not validating.
+    Unchecked,
+    /// The part returned [`Answer::NotImplemented`].
+    NotImplemented,
+    /// The part returned [`Answer::Skipped`].
+    Skipped,
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail | Status::Error | Status::Timeout)
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+            Status::Unsolved => "unsolved",
+            Status::OtherInput => "other-input",
+            Status::Unchecked => "unchecked",
+            Status::NotImplemented => "not-implemented",
+            Status::Skipped => "skipped",
+        })
+    }
+}
+
+/// What a part's answer is checked against.
+pub enum Expected {
+    /// Not validating.
+    Unchecked,
+    /// Validating, but no answer has been recorded.
+    Unsolved,
+    Answer(Value),
+    /// An answer was recorded, but for a different input.
+    OtherInput(Value),
+}
+
+/// Outcome of a single part of a day.
+#[derive(serde::Serialize, Debug)]
+pub struct PartReport {
+    pub day: i32,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    /// The answer was not computed, see [`Answer::HardCoded`].
+    pub hard_coded: bool,
+    #[serde(rename = "parse_ms", serialize_with = "serialize_ms")]
+    pub parse_time: std::time::Duration,
+    #[serde(rename = "solve_ms", serialize_with = "serialize_ms")]
+    pub solve_time: std::time::Duration,
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    pub fn new(
+        day: i32,
+        part: u8,
+        input: &str,
+        result: anyhow::Result<Answer>,
+        expected: Expected,
+        parse_time: std::time::Duration,
+        solve_time: std::time::Duration,
+    ) -> Self {
+        let timed_out = matches!(&result, Err(e) if e.is::<crate::watchdog::TimedOut>());
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(e) => (None, Some(format!("{:#}", e))),
+        };
+
+        let status = match (&answer, &expected) {
+            (None, _) if timed_out => Status::Timeout,
+            (None, _) => Status::Error,
+            (Some(Answer::NotImplemented), _) => Status::NotImplemented,
+            (Some(Answer::Skipped), _) => Status::Skipped,
+            (Some(_), Expected::Unchecked) => Status::Unchecked,
+            (Some(_), Expected::Unsolved) => Status::Unsolved,
+            (Some(_), Expected::OtherInput(_)) => Status::OtherInput,
+            (Some(answer), Expected::Answer(expected))
+                if answer
+                    .value()
+                    .is_some_and(|answer| expected.matches(&answer)) =>
+            {
+                Status::Pass
+            }
+            (Some(_), Expected::Answer(_)) => Status::Fail,
+        };
+        let expected = match expected {
+            Expected::Answer(expected) | Expected::OtherInput(expected) => {
+                Some(expected.to_string())
+            }
+            Expected::Unchecked | Expected::Unsolved => None,
+        };
+        let hard_coded = matches!(answer, Some(Answer::HardCoded(_)));
+        let answer = answer.as_ref().and_then(Answer::value);
+
+        Self {
+            day,
+            part,
+            input: input.to_string(),
+            answer,
+            expected,
+            status,
+            hard_coded,
+            parse_time,
+            solve_time,
+            error,
+        }
+    }
+}
+
+/// Names a part in logs, e.g. `day 05/part 2` or `day 05/example-1/part 2`.
+///
+/// Without a part, this names the parsing step.
+pub fn label(day: i32, input: &str, part: Option<u8>) -> String {
+    let part = part.map_or_else(|| "parse".to_string(), |part| format!("part {}", part));
+    if input == aoc2023::inputs::PUZZLE_INPUT {
+        format!("day {:02}/{}", day, part)
+    } else {
+        format!("day {:02}/{}/{}", day, input, part)
+    }
+}
+
+fn serialize_ms<S: serde::Serializer>(
+    duration: &std::time::Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(as_ms(*duration))
+}
+
+pub fn as_ms(duration: std::time::Duration) -> f64 {
+    duration.as_nanos() as f64 / 1e6
+}
+
+pub fn print(reports: &[PartReport], format: Format) -> anyhow::Result<()> {
+    match format {
+        Format::Table => print_table(reports),
+        Format::Json => println!("{}", serde_json::to_string_pretty(reports)?),
+        Format::Csv => print_csv(reports),
+    }
+    Ok(())
+}
+
+/// Prints the validation summary to stderr, keeping stdout free for the report.
+pub fn print_summary(reports: &[PartReport]) {
+    let count = |status| reports.iter().filter(|r| r.status == status).count();
+
+    eprintln!(
+        "Validation summary: {} passed, {} failed, {} errors, {} timed out, {} unsolved, {} for other inputs, {} not implemented, {} skipped",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Error),
+        count(Status::Timeout),
+        count(Status::Unsolved),
+        count(Status::OtherInput),
+        count(Status::NotImplemented),
+        count(Status::Skipped),
+    );
+
+    for r in reports.iter().filter(|r| r.status == Status::OtherInput) {
+        eprintln!(
+            "  {}: answer recorded for a different input",
+            label(r.day, &r.input, Some(r.part))
+        );
+    }
+
+    for r in reports.iter().filter(|r| r.hard_coded) {
+        eprintln!(
+            "  {}: answer is hard-coded",
+            label(r.day, &r.input, Some(r.part))
+        );
+    }
+
+    for r in reports.iter().filter(|r| r.status.is_failure()) {
+        let label = label(r.day, &r.input, Some(r.part));
+        match &r.error {
+            Some(error) => eprintln!("  {}: error: {}", label, error),
+            None => eprintln!(
+                "  {}: expected {}, actual {}",
+                label,
+                r.expected.as_deref().unwrap_or_default(),
+                r.answer.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+fn print_table(reports: &[PartReport]) {
+    println!(
+        "{:<4} {:<5} {:<10} {:<15} {:>10} {:>10}  {:<20} {:<20} error",
+        "day", "part", "input", "status", "parse", "solve", "answer", "expected"
+    );
+
+    for r in reports {
+        println!(
+            "{:<4} {:<5} {:<10} {:<15} {:>8.3}ms {:>8.3}ms  {:<20} {:<20} {}",
+            format!("{:02}", r.day),
+            r.part,
+            r.input,
+            r.status,
+            as_ms(r.parse_time),
+            as_ms(r.solve_time),
+            r.answer.as_deref().unwrap_or("-"),
+            r.expected.as_deref().unwrap_or("-"),
+            r.error.as_deref().unwrap_or(""),
+        );
+    }
+}
+
+fn print_csv(reports: &[PartReport]) {
+    println!("day,part,input,answer,expected,status,hard_coded,parse_ms,solve_ms,error");
+
+    for r in reports {
+        println!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            r.day,
+            r.part,
+            csv_field(Some(&r.input)),
+            csv_field(r.answer.as_deref()),
+            csv_field(r.expected.as_deref()),
+            r.status,
+            r.hard_coded,
+            as_ms(r.parse_time),
+            as_ms(r.solve_time),
+            csv_field(r.error.as_deref()),
+        );
+    }
+}
+
+/// Quotes a field if needed, as described in RFC 4180.
+fn csv_field(value: Option<&str>) -> String {
+    match value {
+        None => String::new(),
+        Some(value) if value.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value.to_string(),
+    }
+}
@@ -0,0 +1,89 @@
+const TEMPLATE: &str = include_str!("problems/day_00.rs");
+const REGISTRY: &str = "./src/problems/mod.rs";
+
+/// Creates the module, input, answer and example files of a new day and registers it.
+///
+/// Nothing is written if any of the files exist already.
+pub fn new_day(files: &aoc2023::files::Files, day: i32) -> anyhow::Result<()> {
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("Day must be between 1 and 25, got {}", day);
+    }
+
+    let registry = std::fs::read_to_string(REGISTRY).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read '{}', run this from the repository root: {}",
+            REGISTRY,
+            e
+        )
+    })?;
+    let registry = register(&registry, day)?;
+
+    let module = TEMPLATE.replace("Day00", &format!("Day{:02}", day));
+    let new_files = [
+        (format!("./src/problems/day_{:02}.rs", day).into(), module),
+        (files.input_path(day), String::new()),
+        (files.answers_path(day), String::new()),
+        (files.inputs_dir(day).join("example-1.txt"), String::new()),
+        (files.named_answers_path(day, "example-1"), String::new()),
+    ];
+
+    for (path, _) in new_files.iter() {
+        if path.exists() {
+            anyhow::bail!("Refusing to overwrite '{}'", path.display());
+        }
+    }
+
+    for (path, contents) in new_files.iter() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, contents)?;
+        log::info!("Created '{}'", path.display());
+    }
+
+    std::fs::write(REGISTRY, registry)?;
+    log::info!("Registered day {} in '{}'", day, REGISTRY);
+
+    Ok(())
+}
+
+/// Adds the day to the `solutions!` registry, keeping the days in order.
+fn register(registry: &str, day: i32) -> anyhow::Result<String> {
+    let start = registry
+        .find("solutions! {\n")
+        .ok_or_else(|| anyhow::anyhow!("Failed to find the solutions! registry"))?
+        + "solutions! {\n".len();
+    let end = start
+        + registry[start..]
+            .find('}')
+            .ok_or_else(|| anyhow::anyhow!("Failed to find the end of the solutions! registry"))?;
+
+    let mut entries = Vec::new();
+    for line in registry[start..end].lines() {
+        let (registered, _) = line
+            .split_once("=>")
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse registry entry '{}'", line))?;
+        let registered = registered.trim().parse::<i32>()?;
+        if registered == day {
+            anyhow::bail!("Day {} is already registered", day);
+        }
+        entries.push((registered, line.to_string()));
+    }
+
+    entries.push((
+        day,
+        format!("    {} => day_{:02}::Day{:02},", day, day, day),
+    ));
+    entries.sort_by_key(|(day, _)| *day);
+
+    let entries = entries
+        .into_iter()
+        .map(|(_, line)| line + "\n")
+        .collect::<String>();
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        entries,
+        &registry[end..]
+    ))
+}
@@ -0,0 +1,89 @@
+use crate::report::{PartReport, Status};
+
+/// How far a part of the calendar has come, from worst to best.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PartStatus {
+    Failing,
+    NotImplemented,
+    Skipped,
+    /// Produces an answer, but there is no recorded answer to check it against.
+    Solved,
+    /// Returns an answer that was not computed, see [`aoc2023::Answer::HardCoded`].
+    HardCoded,
+    Validated,
+}
+
+impl PartStatus {
+    fn of(report: &PartReport) -> Self {
+        match report.status {
+            _ if report.status.is_failure() => PartStatus::Failing,
+            Status::NotImplemented => PartStatus::NotImplemented,
+            Status::Skipped => PartStatus::Skipped,
+            _ if report.hard_coded => PartStatus::HardCoded,
+            Status::Pass => PartStatus::Validated,
+            _ => PartStatus::Solved,
+        }
+    }
+
+    fn is_star(&self) -> bool {
+        *self == PartStatus::Validated
+    }
+}
+
+impl std::fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            PartStatus::Failing => "failing",
+            PartStatus::NotImplemented => "not implemented",
+            PartStatus::Skipped => "skipped",
+            PartStatus::Solved => "solved",
+            PartStatus::HardCoded => "hard-coded",
+            PartStatus::Validated => "validated",
+        })
+    }
+}
+
+/// Prints the 25 days with the status and runtime of each part.
+///
+/// A part with several inputs shows its worst status and its slowest runtime.
+pub fn print(reports: &[PartReport]) {
+    println!("{:<4} {:<30} {:<30}", "day", "part 1", "part 2");
+
+    let mut stars = 0;
+    for day in 1..=25 {
+        let cells = [1, 2].map(|part| {
+            let reports = reports
+                .iter()
+                .filter(|r| r.day == day && r.part == part)
+                .collect::<Vec<_>>();
+            let status = reports.iter().map(|r| PartStatus::of(r)).min()?;
+            let runtime = reports.iter().map(|r| r.solve_time).max()?;
+            Some((status, runtime))
+        });
+
+        let cells = cells.map(|cell| match cell {
+            Some((status, runtime)) => {
+                if status.is_star() {
+                    stars += 1;
+                }
+                let star = if status.is_star() { '*' } else { ' ' };
+                format!(
+                    "{} {:<15} {:>10.3}ms",
+                    star,
+                    status,
+                    crate::report::as_ms(runtime)
+                )
+            }
+            None => "  -".to_string(),
+        });
+
+        println!(
+            "{:<4} {:<30} {:<30}",
+            format!("{:02}", day),
+            cells[0],
+            cells[1]
+        );
+    }
+
+    println!("{} stars", stars);
+}
@@ -0,0 +1,60 @@
+/// Where a sequence of states starts repeating: the state at step `start + period`
+/// is the same as the one at step `start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the first repeated state.
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step that has the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// The states of a sequence up to its first repetition, see [`unroll`].
+#[derive(Clone, Debug)]
+pub struct Unrolled<S> {
+    pub cycle: Cycle,
+    states: Vec<S>,
+}
+
+impl<S> Unrolled<S> {
+    /// The state after `n` steps, skipping over the repetitions of the cycle.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+/// Finds the cycle of the states reached by repeatedly applying `step` to `start`,
+/// keeping every state until the first repetition so that each one is only stepped to once.
+///
+/// Never returns if the states don't repeat.
+pub fn unroll<S: Eq + std::hash::Hash>(start: S, mut step: impl FnMut(&S) -> S) -> Unrolled<S> {
+    use std::hash::BuildHasher;
+
+    // States are only stored once: looked up by their hash, then compared in full
+    let hasher = hashbrown::hash_map::DefaultHashBuilder::default();
+    let mut seen = hashbrown::HashMap::<u64, Vec<usize>>::new();
+    let mut states = vec![start];
+    loop {
+        let state = states.last().expect("states start with the first state");
+        let same_hash = seen.entry(hasher.hash_one(state)).or_default();
+        if let Some(&start) = same_hash.iter().find(|&&i| &states[i] == state) {
+            states.pop();
+            let period = states.len() - start;
+            return Unrolled {
+                cycle: Cycle { start, period },
+                states,
+            };
+        }
+        same_hash.push(states.len() - 1);
+        states.push(step(state));
+    }
+}
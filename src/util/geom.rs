@@ -0,0 +1,139 @@
+/// One of the four directions in a grid, clockwise starting to the right, with `y` growing downwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Right, Dir4::Down, Dir4::Left, Dir4::Up];
+
+    /// Parses an arrow like the slopes of day 23: `>`, `v`, `<` or `^`.
+    pub fn from_arrow(c: u8) -> Option<Dir4> {
+        match c {
+            b'>' => Some(Dir4::Right),
+            b'v' => Some(Dir4::Down),
+            b'<' => Some(Dir4::Left),
+            b'^' => Some(Dir4::Up),
+            _ => None,
+        }
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Right | Dir4::Left)
+    }
+
+    /// The step taken when moving one cell in this direction.
+    pub fn offset<T: num::Signed>(self) -> Point2<T> {
+        match self {
+            Dir4::Right => Point2::new(T::one(), T::zero()),
+            Dir4::Down => Point2::new(T::zero(), T::one()),
+            Dir4::Left => Point2::new(-T::one(), T::zero()),
+            Dir4::Up => Point2::new(T::zero(), -T::one()),
+        }
+    }
+}
+
+/// Parses the first letter of `R`ight, `D`own, `L`eft or `U`p.
+impl std::str::FromStr for Dir4 {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "R" => Ok(Dir4::Right),
+            "D" => Ok(Dir4::Down),
+            "L" => Ok(Dir4::Left),
+            "U" => Ok(Dir4::Up),
+            _ => anyhow::bail!("Invalid direction: '{}'", s),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: num::Signed + Copy> Point2<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Moves `distance` steps in a direction.
+    pub fn step(self, dir: Dir4, distance: T) -> Self {
+        self + dir.offset() * distance
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+/// Component-wise arithmetic, and scaling by a single value.
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>> std::ops::Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
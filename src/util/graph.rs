@@ -0,0 +1,334 @@
+/// A directed graph stored as adjacency lists, with its nodes interned to dense ids.
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    nodes: Interner<N>,
+    outgoing: Vec<Vec<Edge>>,
+    incoming: Vec<Vec<Edge>>,
+}
+
+/// An edge to the node `to`, or from it in [`Graph::predecessors`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Edge {
+    pub to: usize,
+    pub weight: u64,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: Interner::default(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + std::hash::Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of a node, adding it if it is new.
+    pub fn intern(&mut self, node: N) -> usize {
+        let id = self.nodes.intern(node);
+        if id == self.outgoing.len() {
+            self.outgoing.push(Vec::new());
+            self.incoming.push(Vec::new());
+        }
+        id
+    }
+
+    pub fn id(&self, node: &N) -> Option<usize> {
+        self.nodes.id(node)
+    }
+
+    pub fn node(&self, id: usize) -> &N {
+        &self.nodes.values[id]
+    }
+
+    /// Adds an edge from `from` to `to`, interning both nodes. Returns their ids.
+    pub fn add_edge(&mut self, from: N, to: N, weight: u64) -> (usize, usize) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.outgoing[from].push(Edge { to, weight });
+        self.incoming[to].push(Edge { to: from, weight });
+        (from, to)
+    }
+
+    /// Adds an edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: u64) -> (usize, usize) {
+        let (a, b) = self.add_edge(a, b, weight);
+        self.add_edge(self.node(b).clone(), self.node(a).clone(), weight);
+        (a, b)
+    }
+}
+
+impl<N> Graph<N> {
+    pub fn len(&self) -> usize {
+        self.outgoing.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outgoing.is_empty()
+    }
+
+    pub fn ids(&self) -> std::ops::Range<usize> {
+        0..self.len()
+    }
+
+    /// The edges leaving a node.
+    pub fn neighbours(&self, id: usize) -> &[Edge] {
+        &self.outgoing[id]
+    }
+
+    /// The edges arriving at a node, with `to` pointing back at where they come from.
+    pub fn predecessors(&self, id: usize) -> &[Edge] {
+        &self.incoming[id]
+    }
+
+    /// Orders the nodes so that every edge points forward, failing if there is a cycle.
+    pub fn topological_sort(&self) -> anyhow::Result<Vec<usize>> {
+        let mut in_degree = self.incoming.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ready = self
+            .ids()
+            .filter(|&id| in_degree[id] == 0)
+            .collect::<std::collections::VecDeque<_>>();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+            for edge in self.neighbours(id) {
+                in_degree[edge.to] -= 1;
+                if in_degree[edge.to] == 0 {
+                    ready.push_back(edge.to);
+                }
+            }
+        }
+
+        if order.len() != self.len() {
+            anyhow::bail!("Graph has a cycle, failed to sort it topologically");
+        }
+        Ok(order)
+    }
+}
+
+/// The cheapest way to a goal found by [`dijkstra`] or [`astar`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// From the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+/// Visits every state reachable from `start` in breadth-first order,
+/// together with its distance in steps.
+pub fn bfs<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> impl Iterator<Item = (S, usize)>
+where
+    S: Clone + Eq + std::hash::Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = hashbrown::HashSet::new();
+    seen.insert(start.clone());
+    let mut queue = std::collections::VecDeque::from([(start, 0)]);
+
+    std::iter::from_fn(move || {
+        let (state, distance) = queue.pop_front()?;
+        for next in neighbours(&state) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+        Some((state, distance))
+    })
+}
+
+/// Finds the cheapest path from any of the `starts` to a goal.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + std::hash::Hash,
+    C: Copy + Ord + num::Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::zero(), is_goal)
+}
+
+/// Finds the cheapest path from any of the `starts` to a goal, exploring the states
+/// with the lowest cost plus `heuristic` first.
+///
+/// The heuristic must be consistent: it may not drop by more than the cost of a step,
+/// `heuristic(state) <= step + heuristic(next)`, and is zero at a goal. States are never
+/// explored twice, so with a heuristic that only never overestimates, the path may not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + std::hash::Hash,
+    C: Copy + Ord + num::Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are interned, so the heap and the parents only hold ids
+    let mut states = Interner::default();
+    let mut costs: Vec<Option<C>> = Vec::new();
+    let mut parents = Vec::new();
+    let mut done = Vec::new();
+    let mut heap = std::collections::BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        let id = states.intern(start);
+        if id == costs.len() {
+            costs.push(Some(C::zero()));
+            parents.push(None);
+            done.push(false);
+            heap.push(std::cmp::Reverse((estimate, id)));
+        }
+    }
+
+    while let Some(std::cmp::Reverse((_, id))) = heap.pop() {
+        if done[id] {
+            continue;
+        }
+        done[id] = true;
+
+        let cost = costs[id]?;
+        let state = states.values[id].clone();
+        if is_goal(&state) {
+            let mut path = vec![id];
+            while let Some(parent) = parents[*path.last()?] {
+                path.push(parent);
+            }
+            return Some(Path {
+                cost,
+                states: path
+                    .into_iter()
+                    .rev()
+                    .map(|id| states.values[id].clone())
+                    .collect(),
+            });
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            let next_id = states.intern(next);
+            if next_id == costs.len() {
+                costs.push(None);
+                parents.push(None);
+                done.push(false);
+            }
+
+            if !done[next_id] && costs[next_id].is_none_or(|known| next_cost < known) {
+                costs[next_id] = Some(next_cost);
+                parents[next_id] = Some(id);
+                let estimate = next_cost + heuristic(&states.values[next_id]);
+                heap.push(std::cmp::Reverse((estimate, next_id)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Like [`dijkstra`], for states that `index` maps to distinct numbers below `len`.
+///
+/// The costs are kept in arrays instead of a hash map, and `neighbours` pushes the next states
+/// into a buffer that is reused, so no allocation happens per state.
+pub fn dijkstra_dense<S, C>(
+    starts: impl IntoIterator<Item = S>,
+    len: usize,
+    index: impl Fn(&S) -> usize,
+    mut neighbours: impl FnMut(&S, &mut Vec<(S, C)>),
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone,
+    C: Copy + Ord + num::Zero,
+{
+    let mut states: Vec<Option<S>> = vec![None; len];
+    let mut costs: Vec<Option<C>> = vec![None; len];
+    let mut parents = vec![None; len];
+    let mut done = vec![false; len];
+    let mut heap = std::collections::BinaryHeap::new();
+
+    for start in starts {
+        let id = index(&start);
+        states[id] = Some(start);
+        costs[id] = Some(C::zero());
+        heap.push(std::cmp::Reverse((C::zero(), id)));
+    }
+
+    let mut moves = Vec::new();
+    while let Some(std::cmp::Reverse((cost, id))) = heap.pop() {
+        if done[id] {
+            continue;
+        }
+        done[id] = true;
+
+        let state = states[id].as_ref()?;
+        if is_goal(state) {
+            let mut path = vec![id];
+            while let Some(parent) = parents[*path.last()?] {
+                path.push(parent);
+            }
+            return Some(Path {
+                cost,
+                states: path
+                    .into_iter()
+                    .rev()
+                    .map(|id| states[id].clone())
+                    .collect::<Option<_>>()?,
+            });
+        }
+
+        neighbours(state, &mut moves);
+        for (next, step) in moves.drain(..) {
+            let next_cost = cost + step;
+            let next_id = index(&next);
+            if !done[next_id] && costs[next_id].is_none_or(|known| next_cost < known) {
+                states[next_id] = Some(next);
+                costs[next_id] = Some(next_cost);
+                parents[next_id] = Some(id);
+                heap.push(std::cmp::Reverse((next_cost, next_id)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Hands out dense ids to values, in the order they are first seen.
+#[derive(Clone, Debug)]
+struct Interner<T> {
+    values: Vec<T>,
+    ids: hashbrown::HashMap<T, usize>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            ids: hashbrown::HashMap::new(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + std::hash::Hash> Interner<T> {
+    fn intern(&mut self, value: T) -> usize {
+        if let Some(&id) = self.ids.get(&value) {
+            return id;
+        }
+        let id = self.values.len();
+        self.ids.insert(value.clone(), id);
+        self.values.push(value);
+        id
+    }
+
+    fn id(&self, value: &T) -> Option<usize> {
+        self.ids.get(value).copied()
+    }
+}
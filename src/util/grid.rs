@@ -0,0 +1,268 @@
+use super::geom::{Dir4, Point2};
+
+/// A position in a [`Grid`], as `(x, y)` with `y` growing downwards.
+pub type Pos = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Parses the bytes of each line as a row.
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        Self::parse_with(input, |c, _| Ok(c))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses each byte of each line into a cell. All lines must have the same length.
+    pub fn parse_with(
+        input: &str,
+        mut parse: impl FnMut(u8, Pos) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Self> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            match width {
+                None => width = Some(line.len()),
+                Some(width) if width != line.len() => anyhow::bail!(
+                    "Grid row {} has length {}, expected {}",
+                    y,
+                    line.len(),
+                    width
+                ),
+                Some(_) => {}
+            }
+
+            for (x, &c) in line.as_bytes().iter().enumerate() {
+                cells.push(parse(c, (x, y))?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let index = self.index(pos);
+        Some(&mut self.cells[index])
+    }
+
+    /// Looks up a position of the grid repeated infinitely in every direction.
+    pub fn get_wrapping(&self, (x, y): (i64, i64)) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self[(x, y)]
+    }
+
+    /// Moves from a position by an offset, if the result is inside the grid.
+    pub fn offset(&self, (x, y): Pos, offset: impl Into<Point2<isize>>) -> Option<Pos> {
+        let offset = offset.into();
+        let pos = (
+            x.checked_add_signed(offset.x)?,
+            y.checked_add_signed(offset.y)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The next position in a direction, if it is inside the grid.
+    pub fn neighbour(&self, pos: Pos, dir: Dir4) -> Option<Pos> {
+        self.offset(pos, dir.offset())
+    }
+
+    /// The positions right, down, left and up of a position that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.neighbour(pos, dir))
+    }
+
+    /// The positions around a position, including diagonals, that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        const AROUND: [(isize, isize); 8] = [
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+        ];
+        AROUND
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position whose cell matches.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.enumerate()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks` of a zero sized chunk panics
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        // `step_by` a zero sized step panics
+        self.cells[x..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise: the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .columns()
+                .flat_map(|column| column.rev())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise: the top row becomes the left column.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn index(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> std::ops::Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} is outside of the grid", pos);
+        &self.cells[self.index(pos)]
+    }
+}
+
+impl<T> std::ops::IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside of the grid", pos);
+        let index = self.index(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl std::fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
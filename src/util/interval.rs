@@ -0,0 +1,207 @@
+/// The half-open interval `start..end` of integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: num::PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval `start..=end`.
+    pub fn inclusive(start: T, end: T) -> Self {
+        Self::new(start, end + T::one())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// The overlap of both intervals, empty if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits into the values before `at` and the values from `at` on, either may be empty.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+
+    /// Moves the interval up by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+
+    pub fn range(&self) -> std::ops::Range<T> {
+        self.start..self.end
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: num::PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // Everything touching the new interval is merged into it
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| {
+                Interval::new(merged.start.min(other.start), merged.end.max(other.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for &interval in other.intervals.iter() {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let overlap = a.intersection(b);
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self {
+            intervals: intersection,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+        let mut j = 0;
+        for &interval in self.intervals.iter() {
+            let mut rest = interval;
+
+            // Skip what ends before this interval, then cut out what overlaps it
+            while other.intervals.get(j).is_some_and(|b| b.end <= rest.start) {
+                j += 1;
+            }
+            let mut k = j;
+            while let Some(b) = other.intervals.get(k).filter(|b| b.start < rest.end) {
+                let (before, _) = rest.split_at(b.start);
+                if !before.is_empty() {
+                    difference.push(before);
+                }
+                rest = Interval::new(b.end.max(rest.start), rest.end);
+                k += 1;
+            }
+
+            if !rest.is_empty() {
+                difference.push(rest);
+            }
+        }
+        Self {
+            intervals: difference,
+        }
+    }
+
+    /// Moves every value up by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        Self {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(offset))
+                .collect(),
+        }
+    }
+}
+
+impl<T: num::PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: num::PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/// An N-dimensional box, with one interval per axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize>(pub [Interval<T>; N]);
+
+impl<T: num::PrimInt, const N: usize> Cuboid<T, N> {
+    pub fn axes(&self) -> &[Interval<T>; N] {
+        &self.0
+    }
+
+    /// The number of points inside the box.
+    pub fn volume(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::one(), |volume, interval| volume * interval.len())
+    }
+
+    /// Splits along an axis into the part before `at` and the part from `at` on,
+    /// either may be empty.
+    pub fn split(&self, axis: usize, at: T) -> (Self, Self) {
+        let (before, after) = self.0[axis].split_at(at);
+        let (mut front, mut back) = (*self, *self);
+        front.0[axis] = before;
+        back.0[axis] = after;
+        (front, back)
+    }
+}
@@ -1,3 +1,9 @@
+pub mod cycle;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+
 pub fn parse_space_separated_numbers<T: std::str::FromStr>(line: &str) -> anyhow::Result<Vec<T>>
 where
     <T as std::str::FromStr>::Err: std::fmt::Display,
@@ -34,3 +40,14 @@ pub enum QuadraticSolution {
     OneRoot(f64),
     TwoRoots(f64, f64),
 }
+
+/// Short, stable hash of a puzzle input, used to tell inputs apart.
+pub fn fingerprint(input: &str) -> String {
+    use sha2::Digest;
+
+    sha2::Sha256::digest(input.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
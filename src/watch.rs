@@ -0,0 +1,95 @@
+use crate::report::{PartReport, Status};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Modification times of the files a day's run depends on.
+type Snapshot = std::collections::BTreeMap<std::path::PathBuf, std::time::SystemTime>;
+
+/// Runs the days, then reruns a day whenever its input, parameters, examples or answers change.
+///
+/// Only returns on error; stop it with Ctrl-C.
+pub fn watch(
+    files: &aoc2023::files::Files,
+    days: &[i32],
+    mut run: impl FnMut(i32) -> Vec<PartReport>,
+) -> anyhow::Result<()> {
+    let mut snapshots = Vec::new();
+    for &day in days.iter() {
+        snapshots.push(snapshot(files, day)?);
+        print(&run(day));
+    }
+    log::info!(
+        "Watching the files of days {:?}, press Ctrl-C to stop",
+        days
+    );
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        for (&day, previous) in days.iter().zip(snapshots.iter_mut()) {
+            let current = snapshot(files, day)?;
+            if current == *previous {
+                continue;
+            }
+
+            let changed = current
+                .keys()
+                .chain(previous.keys())
+                .filter(|path| current.get(*path) != previous.get(*path))
+                .collect::<std::collections::BTreeSet<_>>();
+            for path in changed {
+                log::info!("Changed: '{}'", path.display());
+            }
+
+            *previous = current;
+            println!("--- day {:02} ---", day);
+            print(&run(day));
+        }
+    }
+}
+
+fn snapshot(files: &aoc2023::files::Files, day: i32) -> anyhow::Result<Snapshot> {
+    let mut paths = vec![
+        files.input_path(day),
+        files.params_path(day),
+        files.answers_path(day),
+    ];
+    for dir in [files.inputs_dir(day), files.named_answers_dir(day)] {
+        match std::fs::read_dir(&dir) {
+            Ok(entries) => {
+                for entry in entries {
+                    paths.push(entry?.path());
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => anyhow::bail!("Failed to read '{}': {}", dir.display(), e),
+        }
+    }
+
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        // Missing files are part of the snapshot by their absence
+        if let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) {
+            snapshot.insert(path, modified);
+        }
+    }
+    Ok(snapshot)
+}
+
+/// Prints each part with its answer, and the expected answer next to it when they differ.
+fn print(reports: &[PartReport]) {
+    for r in reports {
+        let label = crate::report::label(r.day, &r.input, Some(r.part));
+        let answer = r.answer.as_deref().unwrap_or("-");
+        match (&r.status, &r.error) {
+            (_, Some(error)) => println!("{}: {}: {}", label, r.status, error),
+            (Status::Fail, None) => {
+                println!("{}: {}", label, r.status);
+                println!("  - expected: {}", r.expected.as_deref().unwrap_or("-"));
+                println!("  + actual:   {}", answer);
+            }
+            (_, None) => println!("{}: {} {}", label, r.status, answer),
+        }
+    }
+}
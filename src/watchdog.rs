@@ -0,0 +1,91 @@
+/// Runtime every part should stay below, see the constraints in the README.
+pub const PERFORMANCE_TARGET: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Main thread's default stack size, so recursive solutions behave the same with a timeout.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Error for a part that did not finish within its time budget.
+#[derive(Debug)]
+pub struct TimedOut(pub std::time::Duration);
+
+impl std::fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "timed out after {:?}", self.0)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Runs `f` on a separate thread and stops waiting for it once `timeout` has passed.
+///
+/// Threads cannot be killed, so a timed out part keeps running in the background
+/// until it finishes or the process exits.
+pub fn run<T: Send + 'static>(
+    name: String,
+    timeout: std::time::Duration,
+    f: impl FnOnce() -> anyhow::Result<T> + Send + 'static,
+) -> anyhow::Result<T> {
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::Builder::new()
+        .name(name)
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // The receiver is gone if we already gave up waiting
+            let _ = tx.send(f());
+        })?;
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => Err(TimedOut(timeout).into()),
+        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            anyhow::bail!("Solution thread exited without a result")
+        }
+    }
+}
+
+/// A `--timeout` value, either for all days (`30s`) or for a single one (`23=2m`).
+#[derive(Clone, Debug)]
+pub struct Timeout {
+    pub day: Option<i32>,
+    pub limit: std::time::Duration,
+}
+
+pub fn parse_timeout(s: &str) -> Result<Timeout, String> {
+    let (day, limit) = match s.split_once('=') {
+        Some((day, limit)) => {
+            let day = day
+                .trim()
+                .parse::<i32>()
+                .map_err(|e| format!("Failed to parse day '{}': {}", day, e))?;
+            (Some(day), limit)
+        }
+        None => (None, s),
+    };
+
+    Ok(Timeout {
+        day,
+        limit: parse_duration(limit.trim())?,
+    })
+}
+
+/// Parses durations like `500ms`, `30s` or `2m`. Plain numbers are seconds.
+fn parse_duration(s: &str) -> Result<std::time::Duration, String> {
+    let (value, unit) = s
+        .find(|c: char| c.is_ascii_alphabetic())
+        .map_or((s, ""), |i| s.split_at(i));
+
+    let value = value
+        .parse::<f64>()
+        .map_err(|e| format!("Failed to parse duration '{}': {}", s, e))?;
+
+    let secs = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("Unknown duration unit '{}' in '{}'", unit, s)),
+    };
+
+    std::time::Duration::try_from_secs_f64(secs)
+        .map_err(|e| format!("Invalid duration '{}': {}", s, e))
+}
@@ -0,0 +1,195 @@
+//! Checks the shared puzzle helpers in `aoc2023::util`.
+
+use aoc2023::util::cycle;
+use aoc2023::util::geom::{Dir4, Point2};
+use aoc2023::util::grid::Grid;
+use aoc2023::util::interval::{Cuboid, Interval, IntervalSet};
+
+#[test]
+fn grid_rotates_and_transposes() {
+    let grid = Grid::parse("abc\ndef\n").unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(
+        grid.rotate_clockwise().rotate_counter_clockwise(),
+        grid.clone()
+    );
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+
+    let empty = Grid::parse("").unwrap();
+    assert_eq!(empty.columns().count(), 0);
+    assert_eq!(empty.transpose(), empty);
+}
+
+#[test]
+#[should_panic(expected = "outside of the grid")]
+fn grid_column_outside_panics() {
+    let grid = Grid::parse("abc\ndef\n").unwrap();
+    grid.column(3).count();
+}
+
+#[test]
+fn grid_neighbours_stay_inside() {
+    let grid = Grid::parse("abc\ndef\nghi\n").unwrap();
+
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.offset((2, 2), (1, 0)), None);
+    assert_eq!(*grid.get_wrapping((-1, 4)), b'f');
+    assert!(Grid::parse("ab\nc\n").is_err());
+}
+
+#[test]
+fn directions_turn_and_move() {
+    assert_eq!(Dir4::Right.turn_left(), Dir4::Up);
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Down.reverse(), Dir4::Up);
+    assert_eq!("L".parse::<Dir4>().unwrap(), Dir4::Left);
+
+    let start = Point2::new(2_i64, 3);
+    assert_eq!(start.step(Dir4::Up, 5), Point2::new(2, -2));
+    assert_eq!(start.manhattan(Point2::new(-1, 4)), 4);
+}
+
+#[test]
+fn graph_searches_find_shortest_paths() {
+    use aoc2023::util::graph::{self, Graph};
+
+    let mut g = Graph::new();
+    g.add_edge("a", "b", 1);
+    g.add_edge("b", "d", 5);
+    g.add_edge("a", "c", 2);
+    g.add_edge("c", "d", 1);
+
+    let order = g.topological_sort().unwrap();
+    let position = |node| order.iter().position(|&id| g.node(id) == &node);
+    assert!(position("a") < position("c") && position("c") < position("d"));
+
+    let path = graph::dijkstra(
+        [g.id(&"a").unwrap()],
+        |&id| g.neighbours(id).iter().map(|e| (e.to, e.weight)),
+        |&id| *g.node(id) == "d",
+    )
+    .unwrap();
+    assert_eq!(path.cost, 3);
+    assert_eq!(
+        path.states
+            .iter()
+            .map(|&id| *g.node(id))
+            .collect::<Vec<_>>(),
+        ["a", "c", "d"]
+    );
+
+    let path = graph::dijkstra_dense(
+        [g.id(&"a").unwrap()],
+        g.len(),
+        |&id| id,
+        |&id, next| next.extend(g.neighbours(id).iter().map(|e| (e.to, e.weight))),
+        |&id| *g.node(id) == "d",
+    )
+    .unwrap();
+    assert_eq!(path.cost, 3);
+    assert_eq!(path.states.len(), 3);
+
+    let distances = graph::bfs(0_i32, |&n| {
+        [n - 1, n + 1].into_iter().filter(|n| n.abs() <= 3)
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(distances.len(), 7);
+    assert_eq!(distances.last().unwrap().1, 3);
+
+    let path = graph::astar(
+        [(0_i32, 0_i32)],
+        |&(x, y)| [((x + 1, y), 1), ((x, y + 1), 1)],
+        |&(x, y)| (4 - x) + (4 - y),
+        |&pos| pos == (4, 4),
+    )
+    .unwrap();
+    assert_eq!(path.cost, 8);
+
+    g.add_edge("d", "a", 1);
+    assert!(g.topological_sort().is_err());
+}
+
+#[test]
+fn interval_sets_combine() {
+    let set = |intervals: &[(i32, i32)]| {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect::<IntervalSet<_>>()
+    };
+    let a = set(&[(0, 5), (10, 15), (5, 7)]);
+    let b = IntervalSet::from(Interval::new(3, 12));
+
+    assert_eq!(a, set(&[(0, 7), (10, 15)]));
+    assert_eq!(a.union(&b), set(&[(0, 15)]));
+    assert_eq!(a.intersection(&b), set(&[(3, 7), (10, 12)]));
+    assert_eq!(a.difference(&b), set(&[(0, 3), (12, 15)]));
+    assert_eq!(a.shift(-2).min(), Some(-2));
+    assert_eq!(a.difference(&set(&[(-5, 20)])), IntervalSet::new());
+}
+
+#[test]
+fn cuboids_split_on_an_axis() {
+    let cuboid = Cuboid([Interval::new(1, 11), Interval::inclusive(1, 4)]);
+    let (front, back) = cuboid.split(0, 4);
+
+    assert_eq!(cuboid.volume(), 40);
+    assert_eq!((front.volume(), back.volume()), (12, 28));
+    assert_eq!(front.axes()[0], Interval::new(1, 4));
+
+    // Splitting outside of the box leaves one side empty
+    let (front, back) = cuboid.split(1, 0);
+    assert_eq!(front.volume(), 0);
+    assert_eq!(back, cuboid);
+}
+
+#[test]
+fn cycles_are_found_and_skipped() {
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    let unrolled = cycle::unroll(0_u32, |&n| if n == 7 { 3 } else { n + 1 });
+
+    assert_eq!(
+        unrolled.cycle,
+        cycle::Cycle {
+            start: 3,
+            period: 5
+        }
+    );
+    assert_eq!(unrolled.cycle.reduce(2), 2);
+    assert_eq!(unrolled.cycle.reduce(1_000_000_000), 5);
+    assert_eq!(*unrolled.nth(1_000_000_000), 5);
+    assert_eq!(*unrolled.nth(2), 2);
+
+    // States whose hashes all collide are still told apart
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct SameHash(u32);
+    impl std::hash::Hash for SameHash {
+        fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+    }
+    let unrolled = cycle::unroll(SameHash(0), |&SameHash(n)| SameHash((n + 1) % 4));
+    assert_eq!(
+        unrolled.cycle,
+        cycle::Cycle {
+            start: 0,
+            period: 4
+        }
+    );
+    assert_eq!(*unrolled.nth(6), SameHash(2));
+
+    // A sequence that repeats right away
+    let unrolled = cycle::unroll('a', |&c| c);
+    assert_eq!(
+        unrolled.cycle,
+        cycle::Cycle {
+            start: 0,
+            period: 1
+        }
+    );
+}